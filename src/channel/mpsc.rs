@@ -12,8 +12,7 @@ pub trait Sender<T: 'static>: Clone {
     fn is_closed(&self) -> bool;
 }
 
-/// More extensive behavior for `Sender` implemented by tokio and futures (that is, only _not_
-/// implemented by smol).
+/// More extensive behavior for `Sender`.
 pub trait SenderExt<T: 'static>: Sender<T> {
     /// Completes when the receiver has closed.
     fn closed(&mut self) -> impl Future<Output = ()>;
@@ -65,15 +64,55 @@ pub trait UnboundedSender<T: 'static>: Sender<T> {
 
 /// A runtime with an MPSC channel.
 pub trait RuntimeMpsc {
-    type BoundedSender<T: 'static>: BoundedSender<T>;
+    type BoundedSender<T: 'static>: BoundedSender<T> + SenderExt<T>;
     type BoundedReceiver<T: 'static>: Receiver<T>;
 
     fn bounded_channel<T: 'static>(
         buffer: usize,
     ) -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>);
 
-    type UnboundedSender<T: 'static>: UnboundedSender<T>;
+    type UnboundedSender<T: 'static>: UnboundedSender<T> + SenderExt<T>;
     type UnboundedReceiver<T: 'static>: Receiver<T>;
 
     fn unbounded_channel<T: 'static>() -> (Self::UnboundedSender<T>, Self::UnboundedReceiver<T>);
 }
+
+/// A [`SenderExt`] whose futures are [`Send`].
+///
+/// See [`SenderExt`] for the documentation of each method.
+pub trait SendSenderExt<T: Send + 'static>: Sender<T> + Send {
+    /// See [`SenderExt::closed`].
+    fn closed(&mut self) -> impl Future<Output = ()> + Send;
+
+    /// See [`SenderExt::same_channel`].
+    fn same_channel(&self, other: &Self) -> bool;
+}
+
+/// A [`BoundedSender`] whose futures are [`Send`].
+///
+/// See [`BoundedSender`] for the documentation of each method.
+pub trait SendBoundedSender<T: Send + 'static>: Sender<T> + Send + Sync {
+    type TrySendError: Error;
+
+    /// See [`BoundedSender::send`].
+    fn send(&mut self, message: T) -> impl Future<Output = Result<(), Self::SendError>> + Send;
+
+    /// See [`BoundedSender::try_send`].
+    fn try_send(&mut self, message: T) -> Result<(), Self::TrySendError>;
+}
+
+/// A runtime with an MPSC channel whose halves can be sent between threads.
+pub trait SendRuntimeMpsc {
+    type BoundedSender<T: Send + 'static>: SendBoundedSender<T> + SendSenderExt<T>;
+    type BoundedReceiver<T: Send + 'static>: Receiver<T> + Send;
+
+    fn bounded_channel<T: Send + 'static>(
+        buffer: usize,
+    ) -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>);
+
+    type UnboundedSender<T: Send + 'static>: UnboundedSender<T> + SendSenderExt<T> + Send + Sync;
+    type UnboundedReceiver<T: Send + 'static>: Receiver<T> + Send;
+
+    fn unbounded_channel<T: Send + 'static>()
+    -> (Self::UnboundedSender<T>, Self::UnboundedReceiver<T>);
+}
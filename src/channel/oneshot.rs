@@ -51,3 +51,30 @@ pub trait RuntimeOneshot {
 
     fn channel<T>() -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>);
 }
+
+/// A oneshot [`Sender`] that can be sent between threads and whose futures are [`Send`].
+///
+/// See [`Sender`] for the documentation of each method.
+pub trait SendSender<T>: Send {
+    /// See [`Sender::send`].
+    fn send(self, t: T) -> Result<(), T>;
+
+    /// See [`Sender::closed`].
+    fn closed(&mut self) -> impl Future<Output = ()> + Send {
+        std::future::poll_fn(|cx| self.poll_closed(cx))
+    }
+
+    /// See [`Sender::is_closed`].
+    fn is_closed(&self) -> bool;
+
+    /// See [`Sender::poll_closed`].
+    fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()>;
+}
+
+/// A runtime with a oneshot channel whose halves can be sent between threads.
+pub trait SendRuntimeOneshot {
+    type OneshotSender<T: Send>: SendSender<T>;
+    type OneshotReceiver<T: Send>: Receiver<T> + Send;
+
+    fn channel<T: Send>() -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>);
+}
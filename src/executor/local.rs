@@ -1,14 +1,29 @@
 //! Thread-local executor.
 
+use super::JoinError;
+
 /// A thread-local task executor that can spawn futures to be run concurrently.
 pub trait Executor: Sized {
-    type Handle<T: 'static>: super::Handle<T>;
+    type Handle<T: 'static>: super::Handle<T> + 'static;
+
+    /// A set of tasks spawned on this executor.
+    type TaskSet<T: 'static>: TaskSet<T, Executor = Self>;
 
     /// Spawn a future to be run by the executor.
     ///
     /// Returns a handle that can be awaited or canceled.
     fn spawn<T: 'static>(&self, future: impl Future<Output = T> + 'static) -> Self::Handle<T>;
 
+    /// Run a blocking function on a thread dedicated to blocking work.
+    ///
+    /// Use this for CPU-heavy computations or blocking calls that would otherwise stall the
+    /// executor. Returns a handle that can be awaited or canceled, although a function that has
+    /// already started running will always run to completion.
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T>;
+
     /// Run a future to completion.
     fn block_on<T>(&self, future: impl Future<Output = T>) -> T;
 
@@ -16,6 +31,35 @@ pub trait Executor: Sized {
     fn new() -> std::io::Result<Self>;
 }
 
+/// A collection of tasks spawned on an [`Executor`], which can be awaited in the order they
+/// complete.
+///
+/// Dropping the set will cancel every task in it.
+pub trait TaskSet<T: 'static>: Default {
+    /// The executor on which tasks in this set are spawned.
+    type Executor: Executor;
+
+    /// Spawn a future on `executor`, adding it to the set.
+    fn spawn(&mut self, executor: &Self::Executor, future: impl Future<Output = T> + 'static);
+
+    /// Wait for any task in the set to complete, removing it from the set and returning its
+    /// output.
+    ///
+    /// Returns `None` if the set is empty.
+    fn join_next(&mut self) -> impl Future<Output = Option<Result<T, JoinError>>>;
+
+    /// Cancel every task in the set, leaving it empty.
+    fn abort_all(&mut self);
+
+    /// Returns the number of tasks in the set.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the set contains no tasks.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 /// A runtime with a thread-local executor.
 pub trait RuntimeExecutor {
     type Executor: Executor;
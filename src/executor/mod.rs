@@ -1,5 +1,7 @@
 //! Executors for running tasks.
 
+use std::{any::Any, fmt, sync::Mutex};
+
 #[cfg(feature = "exec")]
 mod multi_thread;
 #[cfg(feature = "exec")]
@@ -10,16 +12,247 @@ pub mod local;
 
 /// A handle to a spawned task.
 ///
-/// Dropping the handle will cancel the task. Awaiting it will wait for the task to complete.
-pub trait Handle<T: 'static>: Future<Output = Self::Wrap<T>> {
-    /// A wrapper around the return type of a task.
-    ///
-    /// This is only used by tokio, whose [`JoinHandle`](tokio::task::JoinHandle) returns a
-    /// [`Result`].
-    type Wrap<U>;
+/// Dropping the handle will cancel the task. Awaiting it will wait for the task to complete,
+/// yielding its output, or a [`JoinError`] if the task was cancelled or panicked.
+pub trait Handle<T: 'static>: Future<Output = Result<T, JoinError>> {
+    /// A handle which can cancel the task without owning it.
+    type AbortHandle: AbortHandle;
 
     /// Drops the task _without_ canceling it.
     ///
     /// This is useful if you want a task to run in the background.
     fn detach(self);
+
+    /// Cancels the task.
+    ///
+    /// Awaiting the handle afterwards yields a [cancelled](JoinError::is_cancelled) error, unless
+    /// the task had already completed. A function passed to `spawn_blocking` can only be cancelled
+    /// if it hasn't started running yet.
+    fn abort(&self);
+
+    /// Returns `true` if the task has completed, either by running to completion, panicking or
+    /// being cancelled.
+    fn is_finished(&self) -> bool;
+
+    /// Returns a handle which can cancel the task without owning it.
+    fn abort_handle(&self) -> Self::AbortHandle;
+}
+
+/// A cloneable handle which can cancel a task, created with [`Handle::abort_handle`].
+///
+/// Unlike a [`Handle`], dropping an abort handle doesn't cancel the task.
+pub trait AbortHandle: Clone + Send + Sync + 'static {
+    /// Cancels the task.
+    ///
+    /// See [`Handle::abort`].
+    fn abort(&self);
+
+    /// Returns `true` if the task has completed, either by running to completion, panicking or
+    /// being cancelled.
+    fn is_finished(&self) -> bool;
+}
+
+/// An error returned when awaiting a [`Handle`] whose task failed to complete.
+///
+/// Panics inside a task are caught by every implementation, so a panicking task never takes down
+/// its executor. The panic payload can be retrieved with [`into_panic`](JoinError::into_panic),
+/// e.g. to resume the panic with [`std::panic::resume_unwind`].
+#[derive(thiserror::Error)]
+#[error("{repr}")]
+pub struct JoinError {
+    repr: Repr,
+}
+
+#[derive(thiserror::Error)]
+enum Repr {
+    #[error("task was cancelled")]
+    Cancelled,
+    // The payload is only `Send`, so it's behind a mutex to make the error `Sync`. The mutex is
+    // only locked to format the message, and taken apart by `try_into_panic`.
+    #[error("task panicked{}", panic_message(.0))]
+    Panic(Mutex<Box<dyn Any + Send>>),
+}
+
+fn panic_message(payload: &Mutex<Box<dyn Any + Send>>) -> String {
+    let payload = payload.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        format!(" with message {message:?}")
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        format!(" with message {message:?}")
+    } else {
+        String::new()
+    }
+}
+
+impl JoinError {
+    /// Creates an error for a task that was cancelled before it could complete.
+    pub fn cancelled() -> Self {
+        Self {
+            repr: Repr::Cancelled,
+        }
+    }
+
+    /// Creates an error for a task that panicked with the given payload.
+    pub fn panic(payload: Box<dyn Any + Send>) -> Self {
+        Self {
+            repr: Repr::Panic(Mutex::new(payload)),
+        }
+    }
+
+    /// Returns `true` if the task was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.repr, Repr::Cancelled)
+    }
+
+    /// Returns `true` if the task panicked.
+    pub fn is_panic(&self) -> bool {
+        matches!(self.repr, Repr::Panic(_))
+    }
+
+    /// Consumes the error, returning the payload the task panicked with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the task was cancelled rather than panicking. Use
+    /// [`try_into_panic`](JoinError::try_into_panic) to handle both cases.
+    #[track_caller]
+    pub fn into_panic(self) -> Box<dyn Any + Send> {
+        self.try_into_panic()
+            .expect("`JoinError` reason is not a panic.")
+    }
+
+    /// Consumes the error, returning the payload the task panicked with if it did, or the error
+    /// itself otherwise.
+    pub fn try_into_panic(self) -> Result<Box<dyn Any + Send>, Self> {
+        match self.repr {
+            Repr::Panic(payload) => Ok(payload.into_inner().unwrap_or_else(|e| e.into_inner())),
+            Repr::Cancelled => Err(self),
+        }
+    }
+}
+
+impl fmt::Debug for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "JoinError::{:?}", self.repr)
+    }
+}
+
+impl fmt::Debug for Repr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cancelled => write!(f, "Cancelled"),
+            Self::Panic(_) => write!(f, "Panic(..)"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "exec"))]
+mod tests {
+    use super::{AbortHandle, Executor, Handle, RuntimeExecutor};
+    use ::futures::executor::block_on;
+    use std::time::{Duration, Instant};
+
+    /// Runs each of the given generic tests with every enabled runtime.
+    macro_rules! with_each_runtime {
+        ($($test:ident),* $(,)?) => {
+            #[cfg(feature = "tokio")]
+            mod tokio {
+                $(#[test]
+                fn $test() {
+                    super::$test::<crate::runtime::Tokio>();
+                })*
+            }
+
+            #[cfg(feature = "smol")]
+            mod smol {
+                $(#[test]
+                fn $test() {
+                    super::$test::<crate::runtime::Smol>();
+                })*
+            }
+
+            #[cfg(feature = "futures")]
+            mod futures {
+                $(#[test]
+                fn $test() {
+                    super::$test::<crate::runtime::Futures>();
+                })*
+            }
+        };
+    }
+
+    with_each_runtime!(
+        panic_is_caught,
+        abort_cancels_task,
+        abort_handle_cancels_task,
+        is_finished_once_complete,
+        shutdown_cancels_pending_tasks,
+    );
+
+    fn panic_is_caught<R: RuntimeExecutor>() {
+        let executor = R::Executor::new().unwrap();
+        let handle = executor.spawn(async { std::panic::panic_any(42_u32) });
+        let error = executor.block_on(handle).unwrap_err();
+        assert!(error.is_panic());
+        assert!(!error.is_cancelled());
+        assert_eq!(*error.into_panic().downcast::<u32>().unwrap(), 42);
+    }
+
+    /// Waits for `is_finished` to return `true`, failing after a few seconds.
+    fn wait_until_finished(is_finished: impl Fn() -> bool) {
+        let start = Instant::now();
+        while !is_finished() {
+            assert!(
+                start.elapsed() < Duration::from_secs(5),
+                "task never finished"
+            );
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    fn abort_cancels_task<R: RuntimeExecutor>() {
+        let executor = R::Executor::new().unwrap();
+        let handle = executor.spawn(std::future::pending::<()>());
+        handle.abort();
+        wait_until_finished(|| handle.is_finished());
+        let error = executor.block_on(handle).unwrap_err();
+        assert!(error.is_cancelled());
+        assert!(!error.is_panic());
+    }
+
+    fn abort_handle_cancels_task<R: RuntimeExecutor>() {
+        let executor = R::Executor::new().unwrap();
+        let handle = executor.spawn(std::future::pending::<()>());
+        let abort = handle.abort_handle();
+        assert!(!abort.is_finished());
+        abort.clone().abort();
+        wait_until_finished(|| abort.is_finished());
+        assert!(executor.block_on(handle).unwrap_err().is_cancelled());
+    }
+
+    fn is_finished_once_complete<R: RuntimeExecutor>() {
+        let executor = R::Executor::new().unwrap();
+        let (sender, receiver) = ::futures::channel::oneshot::channel();
+        let handle = executor.spawn(async { receiver.await.unwrap() });
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(!handle.is_finished());
+        sender.send(1).unwrap();
+        wait_until_finished(|| handle.is_finished());
+        assert_eq!(executor.block_on(handle).unwrap(), 1);
+    }
+
+    fn shutdown_cancels_pending_tasks<R: RuntimeExecutor>() {
+        const TIMEOUT: Duration = Duration::from_secs(5);
+
+        let executor = R::Executor::new().unwrap();
+        let quick = executor.spawn(async { 1 });
+        let stuck = executor.spawn(std::future::pending::<()>());
+        wait_until_finished(|| quick.is_finished());
+
+        let start = Instant::now();
+        executor.shutdown_timeout(TIMEOUT);
+        assert_eq!(block_on(quick).unwrap(), 1);
+        assert!(block_on(stuck).unwrap_err().is_cancelled());
+        assert!(start.elapsed() < TIMEOUT);
+    }
 }
@@ -1,7 +1,19 @@
+use super::JoinError;
+use std::time::Duration;
+
 /// A multi-threaded task executor that can spawn futures to be run concurrently.
 pub trait Executor: Sized {
     type Handle<T: 'static>: super::Handle<T>;
 
+    /// A set of tasks spawned on this executor.
+    type TaskSet<T: Send + 'static>: TaskSet<T, Executor = Self>;
+
+    /// A cheaply cloneable handle for spawning tasks onto this executor.
+    type Spawner: Spawner;
+
+    /// A builder for configuring a new executor.
+    type Builder: ExecutorBuilder<Executor = Self>;
+
     /// Spawn a future to be run by the executor.
     ///
     /// Returns a handle that can be awaited or canceled.
@@ -10,14 +22,198 @@ pub trait Executor: Sized {
         future: impl Future<Output = T> + Send + 'static,
     ) -> Self::Handle<T>;
 
+    /// Run a blocking function on a thread dedicated to blocking work.
+    ///
+    /// Use this for CPU-heavy computations or blocking calls that would otherwise stall the
+    /// executor. Returns a handle that can be awaited or canceled, although a function that has
+    /// already started running will always run to completion.
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T>;
+
     /// Run a future to completion.
     fn block_on<T>(&self, future: impl Future<Output = T>) -> T;
 
-    /// Create a new executor.
-    fn new() -> std::io::Result<Self>;
+    /// Shut down the executor, waiting up to `timeout` for its threads to stop.
+    ///
+    /// Every task that hasn't completed is cancelled, so awaiting its handle yields a
+    /// [cancelled](JoinError::is_cancelled) error, as does awaiting a task spawned through a
+    /// [`Spawner`] afterwards. A task is only dropped once the thread running it gets control back,
+    /// so a task stuck in blocking code will hold up its thread until it yields.
+    ///
+    /// Functions passed to [`spawn_blocking`](Executor::spawn_blocking) are the exception, since a
+    /// function that has already started can't be interrupted: it is left to complete, and whether
+    /// the ones that haven't started yet still run, or what their handles resolve to, depends on
+    /// the implementation. Any threads still running once `timeout` has elapsed are left to stop in
+    /// the background.
+    ///
+    /// Dropping the executor shuts it down as well, although tokio will then wait for any running
+    /// blocking functions to complete.
+    fn shutdown_timeout(self, timeout: Duration);
+
+    /// Shut down the executor without waiting for its threads to stop.
+    ///
+    /// This is equivalent to calling [`shutdown_timeout`](Executor::shutdown_timeout) with a zero
+    /// timeout.
+    fn shutdown_background(self) {
+        self.shutdown_timeout(Duration::ZERO);
+    }
+
+    /// Create a new executor with the default configuration.
+    ///
+    /// This is equivalent to `Self::builder().build()`.
+    fn new() -> std::io::Result<Self> {
+        Self::builder().build()
+    }
+
+    /// Returns a builder for configuring a new executor.
+    fn builder() -> Self::Builder;
+
+    /// Returns a spawner for this executor.
+    fn spawner(&self) -> Self::Spawner;
+
+    /// Returns a spawner for the executor running the current task.
+    ///
+    /// This also works from within [`block_on`](Executor::block_on).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from outside of an executor of this type. Use
+    /// [`try_current`](Executor::try_current) to handle this case.
+    #[track_caller]
+    fn current() -> Self::Spawner {
+        Self::try_current().expect("not called from within an executor")
+    }
+
+    /// Returns a spawner for the executor running the current task, or `None` if called from
+    /// outside of an executor of this type.
+    fn try_current() -> Option<Self::Spawner>;
+}
+
+/// A builder for configuring an [`Executor`], created with [`Executor::builder`].
+///
+/// Any setting that isn't configured is left at the implementation's default.
+pub trait ExecutorBuilder {
+    /// The executor built by this builder.
+    type Executor: Executor;
+
+    /// Sets the number of worker threads the executor will use to run tasks.
+    ///
+    /// The default is the number of CPU cores available to the process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is zero.
+    fn worker_threads(&mut self, count: usize) -> &mut Self;
+
+    /// Sets the prefix used to name the executor's threads.
+    ///
+    /// Threads are named `{prefix}-{n}`, where `n` counts up from zero.
+    fn thread_name_prefix(&mut self, prefix: impl Into<String>) -> &mut Self;
+
+    /// Sets the stack size, in bytes, of the executor's threads.
+    fn thread_stack_size(&mut self, size: usize) -> &mut Self;
+
+    /// Sets a function to run on each of the executor's threads right after it starts.
+    ///
+    /// Smol's blocking threads are shared by the whole process, so they don't run this hook.
+    fn on_thread_start(&mut self, f: impl Fn() + Send + Sync + 'static) -> &mut Self;
+
+    /// Sets a function to run on each of the executor's threads right before it stops.
+    ///
+    /// Smol's blocking threads are shared by the whole process, so they don't run this hook.
+    fn on_thread_stop(&mut self, f: impl Fn() + Send + Sync + 'static) -> &mut Self;
+
+    /// Sets the maximum number of functions passed to
+    /// [`spawn_blocking`](Executor::spawn_blocking) that can run at once.
+    ///
+    /// Functions spawned beyond this limit are queued until one of the running ones completes. The
+    /// default is 512, or 500 with smol.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is zero.
+    fn max_blocking_threads(&mut self, count: usize) -> &mut Self;
+
+    /// Creates the configured executor.
+    fn build(&mut self) -> std::io::Result<Self::Executor>;
 }
+
+/// A cheaply cloneable handle for spawning tasks onto an [`Executor`].
 ///
+/// Unlike an executor, a spawner can be obtained from anywhere inside a task with
+/// [`Executor::current`], so it doesn't need to be passed down to every function that spawns.
+pub trait Spawner: Clone + Send + Sync + 'static {
+    type Handle<T: Send + 'static>: super::Handle<T> + Send;
+
+    /// Spawn a future to be run by the executor.
+    ///
+    /// See [`Executor::spawn`].
+    fn spawn<T: Send + 'static>(
+        &self,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Self::Handle<T>;
+
+    /// Run a blocking function on a thread dedicated to blocking work.
+    ///
+    /// See [`Executor::spawn_blocking`].
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T>;
+}
+
+/// A collection of tasks spawned on an [`Executor`], which can be awaited in the order they
+/// complete.
+///
+/// Dropping the set will cancel every task in it.
+pub trait TaskSet<T: Send + 'static>: Default {
+    /// The executor on which tasks in this set are spawned.
+    type Executor: Executor;
+
+    /// Spawn a future on `executor`, adding it to the set.
+    fn spawn(
+        &mut self,
+        executor: &Self::Executor,
+        future: impl Future<Output = T> + Send + 'static,
+    );
+
+    /// Wait for any task in the set to complete, removing it from the set and returning its
+    /// output.
+    ///
+    /// Returns `None` if the set is empty.
+    fn join_next(&mut self) -> impl Future<Output = Option<Result<T, JoinError>>>;
+
+    /// Cancel every task in the set, leaving it empty.
+    fn abort_all(&mut self);
+
+    /// Returns the number of tasks in the set.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the set contains no tasks.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 /// A runtime with a multi-threaded executor.
 pub trait RuntimeExecutor {
+    /// The executor of this runtime.
+    ///
+    /// With smol and futures, this is a wrapper around `smol::Executor` or
+    /// `futures::executor::ThreadPool` rather than the type itself, so that it can keep track of
+    /// the current executor and of its worker threads.
     type Executor: Executor;
+
+    /// See [`Executor::current`].
+    #[track_caller]
+    fn current() -> <Self::Executor as Executor>::Spawner {
+        Self::Executor::current()
+    }
+
+    /// See [`Executor::try_current`].
+    fn try_current() -> Option<<Self::Executor as Executor>::Spawner> {
+        Self::Executor::try_current()
+    }
 }
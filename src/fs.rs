@@ -14,7 +14,7 @@ use std::{
 
 pub trait Fs {
     /// The file struct associated with this runtime.
-    type File: File;
+    type File: File<OpenOptions: OpenOptions<File = Self::File>>;
 
     /// The directory entry struct associated with this runtime.
     type DirEntry: DirEntry;
@@ -191,6 +191,9 @@ pub trait File: AsRawFd + AsyncRead + AsyncWrite + AsyncSeek + Sized {
 
 /// Options and flags which configure how a file is opened.
 pub trait OpenOptions: Default {
+    /// The file struct opened by these options.
+    type File: File<OpenOptions = Self>;
+
     /// Creates a new set of options with all options set to `false`.
     fn new() -> Self;
 
@@ -228,8 +231,170 @@ pub trait OpenOptions: Default {
     fn create_new(&mut self, create_new: bool) -> &mut Self;
 
     /// Opens a file at `path` with the options specified by `self`.
+    fn open(&self, path: impl AsRef<Path>) -> impl Future<Output = Result<Self::File>>;
+}
+
+/// A [`Fs`] whose futures and associated types are [`Send`].
+///
+/// See [`Fs`] for the documentation of each method.
+pub trait SendFs {
+    /// See [`Fs::File`].
+    type File: SendFile<OpenOptions: SendOpenOptions<File = Self::File>>;
+
+    /// See [`Fs::DirEntry`].
+    type DirEntry: SendDirEntry;
+
+    /// See [`Fs::canonocalize`].
+    fn canonocalize(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<PathBuf>> + Send;
+
+    /// See [`Fs::copy`].
+    fn copy(
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<u64>> + Send;
+
+    /// See [`Fs::create_dir`].
+    fn create_dir(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`Fs::create_dir_all`].
+    fn create_dir_all(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`Fs::hard_link`].
+    fn hard_link(
+        target: impl AsRef<Path> + Send,
+        link: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`Fs::metadata`].
+    fn metadata(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Metadata>> + Send;
+
+    /// See [`Fs::read`].
+    fn read(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Vec<u8>>> + Send;
+
+    /// See [`Fs::read_dir`].
+    fn read_dir(
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<impl Stream<Item = Result<Self::DirEntry>> + Send>> + Send;
+
+    /// See [`Fs::read_link`].
+    fn read_link(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<PathBuf>> + Send;
+
+    /// See [`Fs::read_to_string`].
+    fn read_to_string(path: impl AsRef<Path> + Send)
+    -> impl Future<Output = Result<String>> + Send;
+
+    /// See [`Fs::remove_dir`].
+    fn remove_dir(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`Fs::remove_dir_all`].
+    fn remove_dir_all(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`Fs::remove_file`].
+    fn remove_file(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`Fs::rename`].
+    fn rename(
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`Fs::set_permissions`].
+    fn set_permissions(
+        path: impl AsRef<Path> + Send,
+        perm: Permissions,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`Fs::symlink_metadata`].
+    fn symlink_metadata(
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<Metadata>> + Send;
+
+    /// See [`Fs::write`].
+    fn write(
+        path: impl AsRef<Path> + Send,
+        contents: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<()>> + Send;
+}
+
+/// A [`DirEntry`] that can be sent between threads and whose futures are [`Send`].
+///
+/// See [`DirEntry`] for the documentation of each method.
+pub trait SendDirEntry: Send {
+    /// See [`DirEntry::file_name`].
+    fn file_name(&self) -> OsString;
+
+    /// See [`DirEntry::file_type`].
+    fn file_type(&self) -> impl Future<Output = Result<FileType>> + Send;
+
+    /// See [`DirEntry::metadata`].
+    fn metadata(&self) -> impl Future<Output = Result<Metadata>> + Send;
+
+    /// See [`DirEntry::path`].
+    fn path(&self) -> PathBuf;
+}
+
+/// A [`File`] that can be sent between threads and whose futures are [`Send`].
+///
+/// See [`File`] for the documentation of each method.
+pub trait SendFile: AsRawFd + AsyncRead + AsyncWrite + AsyncSeek + Send + Sized {
+    type OpenOptions: SendOpenOptions;
+
+    /// See [`File::create`].
+    fn create(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Self>> + Send;
+
+    /// See [`File::create_new`].
+    fn create_new(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Self>> + Send;
+
+    /// See [`File::metadata`].
+    fn metadata(&self) -> impl Future<Output = Result<Metadata>> + Send;
+
+    /// See [`File::open`].
+    fn open(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Self>> + Send;
+
+    /// See [`File::set_len`].
+    fn set_len(&self, len: u64) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`File::set_permissions`].
+    fn set_permissions(&self, perm: Permissions) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`File::sync_all`].
+    fn sync_all(&self) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`File::sync_data`].
+    fn sync_data(&self) -> impl Future<Output = Result<()>> + Send;
+}
+
+/// [`OpenOptions`] whose futures are [`Send`].
+///
+/// See [`OpenOptions`] for the documentation of each method.
+pub trait SendOpenOptions: Default + Send + Sync {
+    /// See [`OpenOptions::File`].
+    type File: SendFile<OpenOptions = Self>;
+
+    /// See [`OpenOptions::new`].
+    fn new() -> Self;
+
+    /// See [`OpenOptions::read`].
+    fn read(&mut self, read: bool) -> &mut Self;
+
+    /// See [`OpenOptions::write`].
+    fn write(&mut self, write: bool) -> &mut Self;
+
+    /// See [`OpenOptions::append`].
+    fn append(&mut self, append: bool) -> &mut Self;
+
+    /// See [`OpenOptions::truncate`].
+    fn truncate(&mut self, truncate: bool) -> &mut Self;
+
+    /// See [`OpenOptions::create`].
+    fn create(&mut self, create: bool) -> &mut Self;
+
+    /// See [`OpenOptions::create_new`].
+    fn create_new(&mut self, create_new: bool) -> &mut Self;
+
+    /// See [`OpenOptions::open`].
     fn open(
         &self,
-        path: impl AsRef<Path>,
-    ) -> impl Future<Output = Result<impl File<OpenOptions = Self>>>;
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<Self::File>> + Send;
 }
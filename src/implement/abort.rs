@@ -0,0 +1,98 @@
+//! An [`AbortHandle`](crate::executor::AbortHandle) for executors whose tasks can't be cancelled
+//! through a shared reference.
+
+use std::{
+    future::poll_fn,
+    pin::pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Poll, Waker},
+};
+
+/// A handle which can cancel a task wrapped with [`abortable`].
+#[derive(Clone, Default)]
+pub struct AbortHandle(Arc<State>);
+
+#[derive(Default)]
+struct State {
+    aborted: AtomicBool,
+    finished: AtomicBool,
+    /// The waker of the task, so that it can be woken up to notice it was aborted.
+    waker: Mutex<Option<Waker>>,
+}
+
+impl AbortHandle {
+    /// Returns a handle to a task that has already finished.
+    pub fn finished() -> Self {
+        let handle = Self::default();
+        handle.0.finished.store(true, Ordering::Release);
+        handle
+    }
+
+    /// Returns a guard which marks the task as finished when dropped, for tasks that aren't wrapped
+    /// with [`abortable`].
+    pub fn finished_guard(&self) -> FinishedGuard {
+        FinishedGuard(self.clone())
+    }
+
+    /// Returns `true` if the task was aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.0.aborted.load(Ordering::Acquire)
+    }
+}
+
+impl crate::executor::AbortHandle for AbortHandle {
+    fn abort(&self) {
+        self.0.aborted.store(true, Ordering::Release);
+        if let Some(waker) = self.0.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.0.finished.load(Ordering::Acquire)
+    }
+}
+
+/// Wraps `future` so that it can be cancelled with the returned handle, in which case it completes
+/// with `None`.
+///
+/// The task is considered finished once the returned future completes or is dropped.
+pub fn abortable<F: Future>(future: F) -> (impl Future<Output = Option<F::Output>>, AbortHandle) {
+    let handle = AbortHandle::default();
+    let guard = handle.finished_guard();
+    let task = async move {
+        let guard = guard;
+        let state = &guard.0.0;
+        let mut future = pin!(future);
+        poll_fn(|cx| {
+            // Register the waker before checking the flag, so an abort can't be missed in between.
+            let mut waker = state.waker.lock().unwrap();
+            if !waker
+                .as_ref()
+                .is_some_and(|waker| waker.will_wake(cx.waker()))
+            {
+                *waker = Some(cx.waker().clone());
+            }
+            drop(waker);
+            if state.aborted.load(Ordering::Acquire) {
+                return Poll::Ready(None);
+            }
+            future.as_mut().poll(cx).map(Some)
+        })
+        .await
+    };
+    (task, handle)
+}
+
+/// Marks the task as finished when dropped.
+pub struct FinishedGuard(AbortHandle);
+
+impl Drop for FinishedGuard {
+    fn drop(&mut self) {
+        self.0.0.finished.store(true, Ordering::Release);
+        self.0.0.waker.lock().unwrap().take();
+    }
+}
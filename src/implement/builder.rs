@@ -0,0 +1,185 @@
+//! An [`ExecutorBuilder`](crate::executor::ExecutorBuilder) for executors that don't provide one,
+//! along with the thread configuration it shares with the blocking pools.
+
+#[cfg(feature = "exec")]
+use crate::executor::{Executor, ExecutorBuilder};
+use std::{
+    io,
+    sync::{Arc, Condvar, Mutex},
+    thread,
+};
+#[cfg(feature = "exec")]
+use std::{marker::PhantomData, num::NonZeroUsize, time::Duration};
+
+/// A function run on each thread of an executor when it starts or stops.
+pub(crate) type Hook = Arc<dyn Fn() + Send + Sync>;
+
+/// How to spawn the threads of an executor.
+#[derive(Clone, Default)]
+pub(crate) struct ThreadConfig {
+    pub(crate) name_prefix: Option<String>,
+    pub(crate) stack_size: Option<usize>,
+    pub(crate) on_start: Option<Hook>,
+    pub(crate) on_stop: Option<Hook>,
+}
+
+impl ThreadConfig {
+    /// Spawns the `index`th thread of an executor, which runs `f` between the start and stop hooks
+    /// and is counted in `threads` while it runs.
+    pub(crate) fn spawn(
+        &self,
+        index: usize,
+        threads: &Arc<Threads>,
+        f: impl FnOnce() + Send + 'static,
+    ) -> io::Result<()> {
+        let mut builder = thread::Builder::new();
+        if let Some(prefix) = &self.name_prefix {
+            builder = builder.name(format!("{prefix}-{index}"));
+        }
+        if let Some(size) = self.stack_size {
+            builder = builder.stack_size(size);
+        }
+        let on_start = self.on_start.clone();
+        let on_stop = self.on_stop.clone();
+        threads.started();
+        let guard = StoppedGuard(threads.clone());
+        builder.spawn(move || {
+            let _guard = guard;
+            if let Some(on_start) = on_start {
+                on_start();
+            }
+            f();
+            if let Some(on_stop) = on_stop {
+                on_stop();
+            }
+        })?;
+        Ok(())
+    }
+}
+
+/// Counts the running threads of an executor, so that shutting it down can wait for them to stop.
+#[derive(Default)]
+pub(crate) struct Threads {
+    running: Mutex<usize>,
+    stopped: Condvar,
+}
+
+impl Threads {
+    /// Records that a thread is about to start.
+    pub(crate) fn started(&self) {
+        *self.running.lock().unwrap() += 1;
+    }
+
+    /// Records that a thread has stopped.
+    pub(crate) fn stopped(&self) {
+        *self.running.lock().unwrap() -= 1;
+        self.stopped.notify_all();
+    }
+
+    /// Waits up to `timeout` for every thread to stop.
+    #[cfg(feature = "exec")]
+    pub(crate) fn wait(&self, timeout: Duration) {
+        let running = self.running.lock().unwrap();
+        let _ = self
+            .stopped
+            .wait_timeout_while(running, timeout, |running| *running > 0)
+            .unwrap();
+    }
+}
+
+/// Records that a thread has stopped when dropped, even if the thread panicked or failed to spawn.
+struct StoppedGuard(Arc<Threads>);
+
+impl Drop for StoppedGuard {
+    fn drop(&mut self) {
+        self.0.stopped();
+    }
+}
+
+#[cfg(feature = "exec")]
+/// A builder for an executor of type `E`.
+pub struct Builder<E> {
+    worker_threads: Option<NonZeroUsize>,
+    max_blocking_threads: Option<NonZeroUsize>,
+    thread: ThreadConfig,
+    executor: PhantomData<fn() -> E>,
+}
+
+#[cfg(feature = "exec")]
+/// An executor that can be created from a [`Builder`].
+pub trait Build: Executor {
+    fn build(builder: &Builder<Self>) -> io::Result<Self>;
+}
+
+#[cfg(feature = "exec")]
+impl<E> Default for Builder<E> {
+    fn default() -> Self {
+        Self {
+            worker_threads: None,
+            max_blocking_threads: None,
+            thread: ThreadConfig::default(),
+            executor: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "exec")]
+impl<E> Builder<E> {
+    /// The configured number of worker threads, defaulting to the available parallelism.
+    pub(crate) fn worker_thread_count(&self) -> usize {
+        self.worker_threads
+            .or_else(|| thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get)
+    }
+
+    /// The configured limit on concurrently running blocking functions, if any.
+    pub(crate) fn blocking_limit(&self) -> Option<usize> {
+        self.max_blocking_threads.map(NonZeroUsize::get)
+    }
+
+    /// The configuration of the executor's threads.
+    pub(crate) fn thread_config(&self) -> &ThreadConfig {
+        &self.thread
+    }
+}
+
+#[cfg(feature = "exec")]
+impl<E: Build> ExecutorBuilder for Builder<E> {
+    type Executor = E;
+
+    fn worker_threads(&mut self, count: usize) -> &mut Self {
+        self.worker_threads =
+            Some(NonZeroUsize::new(count).expect("worker threads cannot be set to 0"));
+        self
+    }
+
+    fn thread_name_prefix(&mut self, prefix: impl Into<String>) -> &mut Self {
+        self.thread.name_prefix = Some(prefix.into());
+        self
+    }
+
+    fn thread_stack_size(&mut self, size: usize) -> &mut Self {
+        self.thread.stack_size = Some(size);
+        self
+    }
+
+    fn on_thread_start(&mut self, f: impl Fn() + Send + Sync + 'static) -> &mut Self {
+        self.thread.on_start = Some(Arc::new(f));
+        self
+    }
+
+    fn on_thread_stop(&mut self, f: impl Fn() + Send + Sync + 'static) -> &mut Self {
+        self.thread.on_stop = Some(Arc::new(f));
+        self
+    }
+
+    fn max_blocking_threads(&mut self, count: usize) -> &mut Self {
+        self.max_blocking_threads =
+            Some(NonZeroUsize::new(count).expect("max blocking threads cannot be set to 0"));
+        self
+    }
+
+    fn build(&mut self) -> io::Result<E> {
+        E::build(self)
+    }
+}
@@ -0,0 +1,199 @@
+//! A small thread pool for running blocking code, since `futures` doesn't provide one.
+
+#[cfg(any(feature = "exec", feature = "local-exec"))]
+use super::executor::JoinHandle;
+use crate::implement::builder::{ThreadConfig, Threads};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Condvar, Mutex},
+    time::Duration,
+};
+
+/// The default maximum number of threads a pool will spawn.
+pub(crate) const MAX_THREADS: usize = 512;
+
+/// How long an idle thread waits for more work before exiting.
+const KEEP_ALIVE: Duration = Duration::from_secs(10);
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// The pool shared by everything that isn't configured with a pool of its own.
+#[cfg(any(feature = "local-exec", feature = "fs", feature = "net"))]
+static POOL: std::sync::LazyLock<Arc<Pool>> = std::sync::LazyLock::new(|| {
+    Pool::new(
+        MAX_THREADS,
+        ThreadConfig {
+            name_prefix: Some("byor-blocking".into()),
+            ..ThreadConfig::default()
+        },
+        0,
+        Arc::default(),
+    )
+});
+
+pub(crate) struct Pool {
+    state: Mutex<State>,
+    condvar: Condvar,
+    max_threads: usize,
+    thread: ThreadConfig,
+    threads: Arc<Threads>,
+}
+
+struct State {
+    queue: VecDeque<Job>,
+    idle: usize,
+    threads: usize,
+    /// The index of the next thread to be spawned, used to name it.
+    next_index: usize,
+    /// Set once the pool has been shut down, after which jobs are dropped instead of run.
+    shutdown: bool,
+}
+
+/// Runs `f` on the shared blocking pool, returning a handle to its result.
+///
+/// Dropping the handle before `f` has started prevents it from running.
+#[cfg(feature = "local-exec")]
+pub fn unblock<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> JoinHandle<T> {
+    POOL.unblock(f)
+}
+
+/// Runs `f` on the shared blocking pool once polled and waits for its result, resuming its panic
+/// if it panics.
+///
+/// Unlike with [`unblock`], `f` runs even if the returned future is dropped after being polled.
+#[cfg(any(feature = "fs", feature = "net"))]
+pub(crate) async fn asyncify<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+    submit(f).await
+}
+
+/// Runs `f` on the shared blocking pool right away, returning a future of its result which resumes
+/// its panic if it panics.
+///
+/// `f` runs even if the returned future is dropped without being polled.
+#[cfg(any(feature = "fs", feature = "net"))]
+pub(crate) fn submit<T: Send + 'static>(
+    f: impl FnOnce() -> T + Send + 'static,
+) -> impl Future<Output = T> + Send {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+    POOL.execute(Box::new(move || {
+        let _ = sender.send(std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)));
+    }));
+    async move {
+        match receiver
+            .await
+            .expect("the shared blocking pool is never shut down")
+        {
+            Ok(output) => output,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
+
+impl Pool {
+    /// Creates a pool of up to `max_threads` threads, spawned according to `thread`, indexed from
+    /// `first_index` and counted in `threads`.
+    pub(crate) fn new(
+        max_threads: usize,
+        thread: ThreadConfig,
+        first_index: usize,
+        threads: Arc<Threads>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            state: Mutex::new(State {
+                queue: VecDeque::new(),
+                idle: 0,
+                threads: 0,
+                next_index: first_index,
+                shutdown: false,
+            }),
+            condvar: Condvar::new(),
+            max_threads,
+            thread,
+            threads,
+        })
+    }
+
+    /// Runs `f` on this pool, returning a handle to its result.
+    ///
+    /// Dropping the handle before `f` has started prevents it from running.
+    #[cfg(any(feature = "exec", feature = "local-exec"))]
+    pub(crate) fn unblock<T: Send + 'static>(
+        self: &Arc<Self>,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> JoinHandle<T> {
+        let (task, handle) = JoinHandle::new(async move { f() });
+        self.execute(Box::new(move || futures::executor::block_on(task)));
+        handle
+    }
+
+    /// Cancels every job that hasn't started, and stops the threads once their current job is done.
+    ///
+    /// Jobs submitted afterwards are cancelled right away.
+    #[cfg(feature = "exec")]
+    pub(crate) fn shutdown(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.shutdown = true;
+        let queue = std::mem::take(&mut state.queue);
+        drop(state);
+        self.condvar.notify_all();
+        drop(queue);
+    }
+
+    fn execute(self: &Arc<Self>, job: Job) {
+        let mut state = self.state.lock().unwrap();
+        if state.shutdown {
+            return;
+        }
+        state.queue.push_back(job);
+        if state.idle >= state.queue.len() || state.threads >= self.max_threads {
+            drop(state);
+            self.condvar.notify_one();
+            return;
+        }
+
+        state.threads += 1;
+        let index = state.next_index;
+        state.next_index += 1;
+        drop(state);
+
+        let pool = self.clone();
+        if let Err(error) = self.thread.spawn(index, &self.threads, move || pool.run()) {
+            let mut state = self.state.lock().unwrap();
+            state.threads -= 1;
+            if state.next_index == index + 1 {
+                state.next_index = index;
+            }
+            let alive = state.threads > 0;
+            drop(state);
+            // The job stays queued for the threads that are still alive, if any.
+            assert!(alive, "failed to spawn blocking thread: {error}");
+            self.condvar.notify_one();
+        }
+    }
+
+    fn run(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(job) = state.queue.pop_front() {
+                drop(state);
+                job();
+                state = self.state.lock().unwrap();
+                continue;
+            }
+            if state.shutdown {
+                state.threads -= 1;
+                return;
+            }
+
+            state.idle += 1;
+            let (guard, timeout) = self.condvar.wait_timeout(state, KEEP_ALIVE).unwrap();
+            state = guard;
+            state.idle -= 1;
+
+            if timeout.timed_out() && state.queue.is_empty() {
+                state.threads -= 1;
+                return;
+            }
+        }
+    }
+}
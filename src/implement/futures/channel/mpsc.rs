@@ -78,6 +78,38 @@ impl<T: 'static> Receiver<T> for futures::channel::mpsc::UnboundedReceiver<T> {
     }
 }
 
+impl<T: Send + 'static> SendSenderExt<T> for futures::channel::mpsc::Sender<T> {
+    fn closed(&mut self) -> impl Future<Output = ()> + Send {
+        <Self as SenderExt<T>>::closed(self)
+    }
+
+    fn same_channel(&self, other: &Self) -> bool {
+        <Self as SenderExt<T>>::same_channel(self, other)
+    }
+}
+
+impl<T: Send + 'static> SendBoundedSender<T> for futures::channel::mpsc::Sender<T> {
+    type TrySendError = <Self as BoundedSender<T>>::TrySendError;
+
+    fn send(&mut self, message: T) -> impl Future<Output = Result<(), Self::SendError>> + Send {
+        <Self as BoundedSender<T>>::send(self, message)
+    }
+
+    fn try_send(&mut self, message: T) -> Result<(), Self::TrySendError> {
+        <Self as BoundedSender<T>>::try_send(self, message)
+    }
+}
+
+impl<T: Send + 'static> SendSenderExt<T> for futures::channel::mpsc::UnboundedSender<T> {
+    fn closed(&mut self) -> impl Future<Output = ()> + Send {
+        <Self as SenderExt<T>>::closed(self)
+    }
+
+    fn same_channel(&self, other: &Self) -> bool {
+        <Self as SenderExt<T>>::same_channel(self, other)
+    }
+}
+
 impl RuntimeMpsc for Futures {
     type BoundedSender<T: 'static> = futures::channel::mpsc::Sender<T>;
     type BoundedReceiver<T: 'static> = futures::channel::mpsc::Receiver<T>;
@@ -95,3 +127,22 @@ impl RuntimeMpsc for Futures {
         futures::channel::mpsc::unbounded()
     }
 }
+
+impl SendRuntimeMpsc for Futures {
+    type BoundedSender<T: Send + 'static> = <Self as RuntimeMpsc>::BoundedSender<T>;
+    type BoundedReceiver<T: Send + 'static> = <Self as RuntimeMpsc>::BoundedReceiver<T>;
+
+    fn bounded_channel<T: Send + 'static>(
+        buffer: usize,
+    ) -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>) {
+        <Self as RuntimeMpsc>::bounded_channel(buffer)
+    }
+
+    type UnboundedSender<T: Send + 'static> = <Self as RuntimeMpsc>::UnboundedSender<T>;
+    type UnboundedReceiver<T: Send + 'static> = <Self as RuntimeMpsc>::UnboundedReceiver<T>;
+
+    fn unbounded_channel<T: Send + 'static>()
+    -> (Self::UnboundedSender<T>, Self::UnboundedReceiver<T>) {
+        <Self as RuntimeMpsc>::unbounded_channel()
+    }
+}
@@ -28,6 +28,20 @@ impl<T> Receiver<T> for futures::channel::oneshot::Receiver<T> {
     }
 }
 
+impl<T: Send> SendSender<T> for futures::channel::oneshot::Sender<T> {
+    fn send(self, t: T) -> Result<(), T> {
+        <Self as Sender<T>>::send(self, t)
+    }
+
+    fn is_closed(&self) -> bool {
+        <Self as Sender<T>>::is_closed(self)
+    }
+
+    fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        <Self as Sender<T>>::poll_closed(self, cx)
+    }
+}
+
 impl RuntimeOneshot for Futures {
     type OneshotSender<T> = futures::channel::oneshot::Sender<T>;
     type OneshotReceiver<T> = futures::channel::oneshot::Receiver<T>;
@@ -36,3 +50,12 @@ impl RuntimeOneshot for Futures {
         futures::channel::oneshot::channel()
     }
 }
+
+impl SendRuntimeOneshot for Futures {
+    type OneshotSender<T: Send> = <Self as RuntimeOneshot>::OneshotSender<T>;
+    type OneshotReceiver<T: Send> = <Self as RuntimeOneshot>::OneshotReceiver<T>;
+
+    fn channel<T: Send>() -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>) {
+        <Self as RuntimeOneshot>::channel()
+    }
+}
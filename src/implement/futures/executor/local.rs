@@ -1,22 +1,49 @@
-use crate::{executor::local::*, runtime::Futures};
-use futures::task::LocalSpawnExt;
+use crate::{executor::local::*, implement::task_set::HandleSet, runtime::Futures};
+use futures::{
+    executor::{LocalPool, LocalSpawner},
+    task::LocalSpawnExt,
+};
+use std::cell::RefCell;
 
-impl Executor for futures::executor::LocalSpawner {
-    type Handle<T: 'static> = futures::future::RemoteHandle<T>;
+/// A [`LocalPool`] along with a spawner for it.
+///
+/// Tasks can be spawned at any time, but only make progress while
+/// [`block_on`](Executor::block_on) is running.
+pub struct FuturesLocalExecutor {
+    pool: RefCell<LocalPool>,
+    spawner: LocalSpawner,
+}
+
+impl Executor for FuturesLocalExecutor {
+    type Handle<T: 'static> = super::JoinHandle<T>;
+    type TaskSet<T: 'static> = HandleSet<Self, Self::Handle<T>>;
 
     fn spawn<T: 'static>(&self, future: impl Future<Output = T> + 'static) -> Self::Handle<T> {
-        self.spawn_local_with_handle(future).unwrap()
+        let (task, handle) = super::JoinHandle::new(future);
+        self.spawner.spawn_local(task).unwrap();
+        handle
+    }
+
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T> {
+        crate::implement::futures::blocking::unblock(f)
     }
 
     fn block_on<T>(&self, future: impl Future<Output = T>) -> T {
-        futures::executor::block_on(future)
+        self.pool.borrow_mut().run_until(future)
     }
 
     fn new() -> std::io::Result<Self> {
-        Ok(futures::executor::LocalPool::new().spawner())
+        let pool = LocalPool::new();
+        Ok(Self {
+            spawner: pool.spawner(),
+            pool: RefCell::new(pool),
+        })
     }
 }
 
 impl RuntimeExecutor for Futures {
-    type Executor = futures::executor::LocalSpawner;
+    type Executor = FuturesLocalExecutor;
 }
@@ -4,10 +4,81 @@ mod multi_thread;
 #[cfg(feature = "local-exec")]
 mod local;
 
-impl<T: 'static> crate::executor::Handle<T> for futures::future::RemoteHandle<T> {
-    type Wrap<U> = U;
+use crate::{
+    executor::{AbortHandle as _, JoinError},
+    implement::abort::{AbortHandle, abortable},
+};
+use futures::{channel::oneshot, future::FutureExt};
+use std::{
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    task::{Context, Poll},
+    thread,
+};
 
-    fn detach(self) {
-        self.forget();
+/// A [`Handle`](crate::executor::Handle) to a task spawned on a `futures` executor.
+pub struct JoinHandle<T> {
+    receiver: oneshot::Receiver<thread::Result<T>>,
+    abort: AbortHandle,
+    detached: bool,
+}
+
+impl<T> JoinHandle<T> {
+    /// Wraps `future` into a task ready to be spawned, returning it along with its handle.
+    ///
+    /// The task catches any panic produced by `future`, and stops as soon as the handle is dropped.
+    pub(super) fn new<F: Future<Output = T>>(future: F) -> (impl Future<Output = ()>, Self) {
+        let (sender, receiver) = oneshot::channel();
+        let (task, abort) = abortable(async move {
+            let output = AssertUnwindSafe(future).catch_unwind().await;
+            let _ = sender.send(output);
+        });
+        let task = task.map(drop);
+        let handle = Self {
+            receiver,
+            abort,
+            detached: false,
+        };
+        (task, handle)
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.receiver.poll_unpin(cx).map(|output| match output {
+            Ok(Ok(output)) => Ok(output),
+            Ok(Err(payload)) => Err(JoinError::panic(payload)),
+            Err(oneshot::Canceled) => Err(JoinError::cancelled()),
+        })
+    }
+}
+
+impl<T> Drop for JoinHandle<T> {
+    fn drop(&mut self) {
+        if !self.detached {
+            self.abort.abort();
+        }
+    }
+}
+
+impl<T: 'static> crate::executor::Handle<T> for JoinHandle<T> {
+    type AbortHandle = AbortHandle;
+
+    fn detach(mut self) {
+        self.detached = true;
+    }
+
+    fn abort(&self) {
+        self.abort.abort();
+    }
+
+    fn is_finished(&self) -> bool {
+        self.abort.is_finished()
+    }
+
+    fn abort_handle(&self) -> Self::AbortHandle {
+        self.abort.clone()
     }
 }
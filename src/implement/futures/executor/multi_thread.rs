@@ -1,24 +1,300 @@
-use crate::{executor::*, runtime::Futures};
+use super::JoinHandle;
+use crate::{
+    executor::*,
+    implement::{
+        builder::{Build, Builder, Threads},
+        futures::blocking::{MAX_THREADS, Pool},
+        task_set::HandleSet,
+    },
+    runtime::Futures,
+};
+use futures::{
+    channel::oneshot,
+    executor::ThreadPool,
+    future::{self, FutureExt, Shared},
+};
+use std::{
+    cell::RefCell,
+    pin::{Pin, pin},
+    sync::{Arc, Mutex, RwLock},
+    task::{Context, Poll, Wake, Waker},
+    time::Duration,
+};
 
-impl Executor for futures::executor::ThreadPool {
-    type Handle<T: 'static> = futures::future::RemoteHandle<T>;
+thread_local! {
+    static CURRENT: RefCell<Option<FuturesSpawner>> = const { RefCell::new(None) };
+}
+
+/// A [`futures::executor::ThreadPool`].
+pub struct FuturesExecutor {
+    spawner: FuturesSpawner,
+    threads: Arc<Threads>,
+}
+
+/// A [`Spawner`] for a [`FuturesExecutor`].
+#[derive(Clone)]
+pub struct FuturesSpawner {
+    inner: Arc<Inner>,
+}
+
+/// The state shared by a [`FuturesExecutor`] and its spawners.
+struct Inner {
+    /// The thread pool, which is taken out when the executor shuts down.
+    ///
+    /// The pool only stops once every clone of it has been dropped, so spawners and worker threads
+    /// can't hold one directly.
+    pool: RwLock<Option<ThreadPool>>,
+    blocking: Arc<Pool>,
+    /// Dropped when the executor shuts down, completing `cancelled`.
+    shutdown: Mutex<Option<oneshot::Sender<()>>>,
+    /// Completes when the executor shuts down, at which point every task stops.
+    cancelled: Shared<oneshot::Receiver<()>>,
+}
+
+impl Inner {
+    fn shutdown(&self) {
+        self.pool.write().unwrap().take();
+        self.shutdown.lock().unwrap().take();
+        self.blocking.shutdown();
+    }
+}
+
+impl Executor for FuturesExecutor {
+    type Handle<T: 'static> = JoinHandle<T>;
+    type TaskSet<T: Send + 'static> = HandleSet<Self, Self::Handle<T>>;
+    type Spawner = FuturesSpawner;
+    type Builder = Builder<Self>;
 
     fn spawn<T: Send + 'static>(
         &self,
         future: impl Future<Output = T> + Send + 'static,
     ) -> Self::Handle<T> {
-        futures::task::SpawnExt::spawn_with_handle(self, future).unwrap()
+        self.spawner.spawn(future)
+    }
+
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T> {
+        self.spawner.spawn_blocking(f)
     }
 
     fn block_on<T>(&self, future: impl Future<Output = T>) -> T {
+        let _guard = CurrentGuard(CURRENT.replace(Some(self.spawner.clone())));
         futures::executor::block_on(future)
     }
 
-    fn new() -> std::io::Result<Self> {
-        Ok(futures::executor::ThreadPool::new().unwrap())
+    fn shutdown_timeout(self, timeout: Duration) {
+        self.spawner.inner.shutdown();
+        self.threads.wait(timeout);
+    }
+
+    fn builder() -> Self::Builder {
+        Builder::default()
+    }
+
+    fn spawner(&self) -> Self::Spawner {
+        self.spawner.clone()
+    }
+
+    fn try_current() -> Option<Self::Spawner> {
+        CURRENT.with_borrow(Clone::clone)
+    }
+}
+
+impl Build for FuturesExecutor {
+    fn build(builder: &Builder<Self>) -> std::io::Result<Self> {
+        let workers = builder.worker_thread_count();
+        let thread = builder.thread_config();
+        let threads = Arc::new(Threads::default());
+        let (shutdown, cancelled) = oneshot::channel();
+        let spawner = FuturesSpawner {
+            inner: Arc::new(Inner {
+                pool: RwLock::new(None),
+                // Blocking threads are numbered after the worker threads so that their names
+                // don't collide.
+                blocking: Pool::new(
+                    builder.blocking_limit().unwrap_or(MAX_THREADS),
+                    thread.clone(),
+                    workers,
+                    threads.clone(),
+                ),
+                shutdown: Mutex::new(Some(shutdown)),
+                cancelled: cancelled.shared(),
+            }),
+        };
+
+        let mut pool = ThreadPool::builder();
+        pool.pool_size(workers);
+        if let Some(prefix) = &thread.name_prefix {
+            pool.name_prefix(format!("{prefix}-"));
+        }
+        if let Some(size) = thread.stack_size {
+            pool.stack_size(size);
+        }
+        pool.after_start({
+            let spawner = spawner.clone();
+            let on_start = thread.on_start.clone();
+            move |_| {
+                CURRENT.set(Some(spawner.clone()));
+                if let Some(on_start) = &on_start {
+                    on_start();
+                }
+            }
+        });
+        pool.before_stop({
+            let threads = threads.clone();
+            let on_stop = thread.on_stop.clone();
+            move |_| {
+                if let Some(on_stop) = &on_stop {
+                    on_stop();
+                }
+                threads.stopped();
+            }
+        });
+        for _ in 0..workers {
+            threads.started();
+        }
+
+        *spawner.inner.pool.write().unwrap() = Some(pool.create()?);
+        Ok(Self { spawner, threads })
+    }
+}
+
+impl Drop for FuturesExecutor {
+    fn drop(&mut self) {
+        self.spawner.inner.shutdown();
+    }
+}
+
+impl Spawner for FuturesSpawner {
+    type Handle<T: Send + 'static> = JoinHandle<T>;
+
+    fn spawn<T: Send + 'static>(
+        &self,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Self::Handle<T> {
+        let (task, handle) = JoinHandle::new(future);
+        if let Some(pool) = &*self.inner.pool.read().unwrap() {
+            let cancelled = self.inner.cancelled.clone();
+            pool.spawn_ok(PoolTask::new(async move {
+                future::select(pin!(task), cancelled).await;
+            }));
+        }
+        // Otherwise the executor has been shut down, and dropping the task cancels it.
+        handle
+    }
+
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T> {
+        self.inner.blocking.unblock(f)
+    }
+}
+
+/// A task spawned on the pool, which is polled with a waker that forwards to the pool's own.
+///
+/// This works around two quirks of the pool. First, its wakers hold a clone of it, so a waker
+/// outliving its task, e.g. one left registered with a channel whose sender is still alive, would
+/// keep the pool from stopping. The forwarding waker releases the pool's waker once the task is
+/// dropped. Second, a task that wakes itself while being polled is polled again right away rather
+/// than being queued, so yielding wouldn't let other tasks run. Such wakes are deferred to the back
+/// of the pool's queue instead.
+struct PoolTask {
+    future: Pin<Box<dyn Future<Output = ()> + Send>>,
+    waker: Arc<ForwardWaker>,
+}
+
+#[derive(Default)]
+struct ForwardWaker(Mutex<ForwardState>);
+
+#[derive(Default)]
+struct ForwardState {
+    /// The pool's waker for the task, until the task is dropped.
+    waker: Option<Waker>,
+    /// Whether the task is currently being polled.
+    polling: bool,
+    /// Whether the task was woken while being polled.
+    woken: bool,
+}
+
+impl PoolTask {
+    fn new(future: impl Future<Output = ()> + Send + 'static) -> Self {
+        Self {
+            future: Box::pin(future),
+            waker: Arc::default(),
+        }
+    }
+}
+
+impl Future for PoolTask {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.waker.0.lock().unwrap();
+        if !state
+            .waker
+            .as_ref()
+            .is_some_and(|waker| waker.will_wake(cx.waker()))
+        {
+            state.waker = Some(cx.waker().clone());
+        }
+        state.polling = true;
+        drop(state);
+
+        let waker = Waker::from(self.waker.clone());
+        let poll = self.future.as_mut().poll(&mut Context::from_waker(&waker));
+
+        let mut state = self.waker.0.lock().unwrap();
+        state.polling = false;
+        let woken = std::mem::take(&mut state.woken);
+        drop(state);
+        if poll.is_pending() && woken {
+            let pool =
+                CURRENT.with_borrow(|spawner| spawner.as_ref()?.inner.pool.read().unwrap().clone());
+            match pool {
+                Some(pool) => {
+                    let waker = cx.waker().clone();
+                    pool.spawn_ok(async move { waker.wake() });
+                }
+                None => cx.waker().wake_by_ref(),
+            }
+        }
+        poll
+    }
+}
+
+impl Drop for PoolTask {
+    fn drop(&mut self) {
+        self.waker.0.lock().unwrap().waker.take();
+    }
+}
+
+impl Wake for ForwardWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let mut state = self.0.lock().unwrap();
+        if state.polling {
+            state.woken = true;
+        } else if let Some(waker) = &state.waker {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+/// Restores the previous current executor when dropped.
+struct CurrentGuard(Option<FuturesSpawner>);
+
+impl Drop for CurrentGuard {
+    fn drop(&mut self) {
+        CURRENT.set(self.0.take());
     }
 }
 
 impl RuntimeExecutor for Futures {
-    type Executor = futures::executor::ThreadPool;
+    type Executor = FuturesExecutor;
 }
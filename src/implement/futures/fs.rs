@@ -0,0 +1,735 @@
+//! Filesystem operations run on the shared blocking pool, since `futures` doesn't provide any.
+
+use super::blocking::{asyncify, submit};
+use crate::fs::*;
+use futures_lite::{AsyncRead, AsyncSeek, AsyncWrite, Stream, stream};
+use std::{
+    collections::VecDeque,
+    ffi::OsString,
+    fs::{FileType, Metadata, Permissions},
+    future::poll_fn,
+    io::{self, Read, Result, Seek, SeekFrom, Write},
+    os::fd::{AsRawFd, RawFd},
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, ready},
+};
+
+/// The most data read or written by a single blocking call.
+const MAX_BUF: usize = 2 * 1024 * 1024;
+
+/// How many directory entries are read by a single blocking call.
+const READ_DIR_BATCH: usize = 32;
+
+impl Fs for crate::runtime::Futures {
+    type File = File;
+    type DirEntry = DirEntry;
+
+    fn canonocalize(path: impl AsRef<Path>) -> impl Future<Output = Result<PathBuf>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::canonicalize(path))
+    }
+    fn copy(from: impl AsRef<Path>, to: impl AsRef<Path>) -> impl Future<Output = Result<u64>> {
+        let (from, to) = (from.as_ref().to_owned(), to.as_ref().to_owned());
+        asyncify(move || std::fs::copy(from, to))
+    }
+    fn create_dir(path: impl AsRef<Path>) -> impl Future<Output = Result<()>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::create_dir(path))
+    }
+    fn create_dir_all(path: impl AsRef<Path>) -> impl Future<Output = Result<()>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::create_dir_all(path))
+    }
+    fn hard_link(
+        target: impl AsRef<Path>,
+        link: impl AsRef<Path>,
+    ) -> impl Future<Output = Result<()>> {
+        let (target, link) = (target.as_ref().to_owned(), link.as_ref().to_owned());
+        asyncify(move || std::fs::hard_link(target, link))
+    }
+    fn metadata(path: impl AsRef<Path>) -> impl Future<Output = Result<Metadata>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::metadata(path))
+    }
+    fn read(path: impl AsRef<Path>) -> impl Future<Output = Result<Vec<u8>>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::read(path))
+    }
+    fn read_dir(
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = Result<impl Stream<Item = Result<Self::DirEntry>>>> {
+        let path = path.as_ref().to_owned();
+        async move {
+            let read_dir = asyncify(move || std::fs::read_dir(path)).await?;
+            Ok(read_dir_stream(read_dir))
+        }
+    }
+    fn read_link(path: impl AsRef<Path>) -> impl Future<Output = Result<PathBuf>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::read_link(path))
+    }
+    fn read_to_string(path: impl AsRef<Path>) -> impl Future<Output = Result<String>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::read_to_string(path))
+    }
+    fn remove_dir(path: impl AsRef<Path>) -> impl Future<Output = Result<()>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::remove_dir(path))
+    }
+    fn remove_dir_all(path: impl AsRef<Path>) -> impl Future<Output = Result<()>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::remove_dir_all(path))
+    }
+    fn remove_file(path: impl AsRef<Path>) -> impl Future<Output = Result<()>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::remove_file(path))
+    }
+    fn rename(from: impl AsRef<Path>, to: impl AsRef<Path>) -> impl Future<Output = Result<()>> {
+        let (from, to) = (from.as_ref().to_owned(), to.as_ref().to_owned());
+        asyncify(move || std::fs::rename(from, to))
+    }
+    fn set_permissions(
+        path: impl AsRef<Path>,
+        perm: Permissions,
+    ) -> impl Future<Output = Result<()>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::set_permissions(path, perm))
+    }
+    fn symlink_metadata(path: impl AsRef<Path>) -> impl Future<Output = Result<Metadata>> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::symlink_metadata(path))
+    }
+    fn write(
+        path: impl AsRef<Path>,
+        contents: impl AsRef<[u8]>,
+    ) -> impl Future<Output = Result<()>> {
+        let (path, contents) = (path.as_ref().to_owned(), contents.as_ref().to_owned());
+        asyncify(move || std::fs::write(path, contents))
+    }
+}
+
+/// Reads the entries of `read_dir` in batches on the blocking pool.
+fn read_dir_stream(read_dir: std::fs::ReadDir) -> impl Stream<Item = Result<DirEntry>> {
+    stream::unfold(
+        (Some(read_dir), VecDeque::new()),
+        |(mut read_dir, mut entries)| async move {
+            if entries.is_empty()
+                && let Some(mut remaining) = read_dir.take()
+            {
+                let batch;
+                (batch, read_dir) = asyncify(move || {
+                    let batch: VecDeque<_> = remaining
+                        .by_ref()
+                        .take(READ_DIR_BATCH)
+                        .map(|entry| entry.map(|entry| DirEntry(Arc::new(entry))))
+                        .collect();
+                    let done = batch.len() < READ_DIR_BATCH;
+                    (batch, (!done).then_some(remaining))
+                })
+                .await;
+                entries = batch;
+            }
+            let entry = entries.pop_front()?;
+            Some((entry, (read_dir, entries)))
+        },
+    )
+}
+
+/// An entry returned by [`Fs::read_dir`] for [`Futures`](crate::runtime::Futures).
+pub struct DirEntry(Arc<std::fs::DirEntry>);
+
+impl crate::fs::DirEntry for DirEntry {
+    fn file_name(&self) -> OsString {
+        self.0.file_name()
+    }
+    fn file_type(&self) -> impl Future<Output = Result<FileType>> {
+        let entry = self.0.clone();
+        asyncify(move || entry.file_type())
+    }
+    fn metadata(&self) -> impl Future<Output = Result<Metadata>> {
+        let entry = self.0.clone();
+        asyncify(move || entry.metadata())
+    }
+    fn path(&self) -> PathBuf {
+        self.0.path()
+    }
+}
+
+/// A [`std::fs::File`] whose reads, writes and seeks run on the blocking pool.
+///
+/// Like tokio's file, reads are buffered ahead of the cursor and writes complete in the
+/// background, so a write error may only be returned by the next write or flush.
+pub struct File {
+    std: Arc<std::fs::File>,
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    state: State,
+    /// The error of a write which completed in the background, returned by the next write or flush.
+    last_write_err: Option<io::Error>,
+}
+
+enum State {
+    /// No operation is running. The buffer holds the data read ahead of the cursor, if any.
+    Idle(Buf),
+    Busy(Pin<Box<dyn Future<Output = (Buf, Operation)> + Send>>),
+}
+
+enum Operation {
+    Read(Result<usize>),
+    Write(Result<()>),
+    Seek(Result<u64>),
+}
+
+#[derive(Default)]
+struct Buf {
+    data: Vec<u8>,
+    /// How much of `data` has already been consumed.
+    pos: usize,
+}
+
+impl Buf {
+    fn remaining(&self) -> &[u8] {
+        &self.data[self.pos..]
+    }
+
+    fn clear(&mut self) {
+        self.data.clear();
+        self.pos = 0;
+    }
+
+    fn copy_to(&mut self, dst: &mut [u8]) -> usize {
+        let len = self.remaining().len().min(dst.len());
+        dst[..len].copy_from_slice(&self.data[self.pos..self.pos + len]);
+        self.pos += len;
+        len
+    }
+
+    fn copy_from(&mut self, src: &[u8]) -> usize {
+        let len = src.len().min(MAX_BUF);
+        self.data.extend_from_slice(&src[..len]);
+        len
+    }
+
+    fn read_from(&mut self, mut file: &std::fs::File, len: usize) -> Result<usize> {
+        self.clear();
+        self.data.resize(len, 0);
+        let result = loop {
+            match file.read(&mut self.data) {
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                result => break result,
+            }
+        };
+        self.data.truncate(*result.as_ref().unwrap_or(&0));
+        result
+    }
+}
+
+impl File {
+    fn new(std: std::fs::File) -> Self {
+        Self {
+            std: Arc::new(std),
+            inner: Mutex::new(Inner {
+                state: State::Idle(Buf::default()),
+                last_write_err: None,
+            }),
+        }
+    }
+
+    /// Waits for writes running in the background to complete, then runs `f` on the blocking pool.
+    async fn asyncify<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&std::fs::File) -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        poll_fn(|cx| self.inner.lock().unwrap().poll_flush(cx)).await?;
+        let std = self.std.clone();
+        asyncify(move || f(&std)).await
+    }
+}
+
+impl Inner {
+    /// Waits for the running operation, if any, returning its result.
+    fn poll_complete(&mut self, cx: &mut Context<'_>) -> Poll<Option<Operation>> {
+        let State::Busy(task) = &mut self.state else {
+            return Poll::Ready(None);
+        };
+        let (buf, operation) = ready!(task.as_mut().poll(cx));
+        self.state = State::Idle(buf);
+        Poll::Ready(Some(operation))
+    }
+
+    fn buf(&mut self) -> &mut Buf {
+        match &mut self.state {
+            State::Idle(buf) => buf,
+            State::Busy(_) => unreachable!("the file is busy"),
+        }
+    }
+
+    /// Starts running `f` on the blocking pool, even if the file is dropped before it completes.
+    /// The file must be idle.
+    fn start(
+        &mut self,
+        std: &Arc<std::fs::File>,
+        f: impl FnOnce(&std::fs::File, &mut Buf) -> Operation + Send + 'static,
+    ) {
+        let mut buf = std::mem::take(self.buf());
+        let std = std.clone();
+        self.state = State::Busy(Box::pin(submit(move || {
+            let operation = f(&std, &mut buf);
+            (buf, operation)
+        })));
+    }
+
+    fn poll_read(
+        &mut self,
+        cx: &mut Context<'_>,
+        std: &Arc<std::fs::File>,
+        dst: &mut [u8],
+    ) -> Poll<Result<usize>> {
+        loop {
+            match ready!(self.poll_complete(cx)) {
+                Some(Operation::Read(Ok(_))) => return Poll::Ready(Ok(self.buf().copy_to(dst))),
+                Some(Operation::Read(Err(error))) => return Poll::Ready(Err(error)),
+                Some(Operation::Write(Err(error))) => self.last_write_err = Some(error),
+                _ => {}
+            }
+            let buf = self.buf();
+            if !buf.remaining().is_empty() || dst.is_empty() {
+                return Poll::Ready(Ok(buf.copy_to(dst)));
+            }
+            let len = dst.len().min(MAX_BUF);
+            self.start(std, move |file, buf| {
+                Operation::Read(buf.read_from(file, len))
+            });
+        }
+    }
+
+    fn poll_write(
+        &mut self,
+        cx: &mut Context<'_>,
+        std: &Arc<std::fs::File>,
+        src: &[u8],
+    ) -> Poll<Result<usize>> {
+        if let Some(Operation::Write(Err(error))) = ready!(self.poll_complete(cx)) {
+            return Poll::Ready(Err(error));
+        }
+        if let Some(error) = self.last_write_err.take() {
+            return Poll::Ready(Err(error));
+        }
+        if src.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        let buf = self.buf();
+        // The file's cursor is past the data read ahead, so it has to be moved back to ours first.
+        let unread = buf.remaining().len() as i64;
+        buf.clear();
+        let len = buf.copy_from(src);
+        self.start(std, move |mut file, buf| {
+            let mut write = || {
+                if unread > 0 {
+                    file.seek(SeekFrom::Current(-unread))?;
+                }
+                file.write_all(&buf.data)
+            };
+            let result = write();
+            buf.clear();
+            Operation::Write(result)
+        });
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_flush(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        if let Some(Operation::Write(Err(error))) = ready!(self.poll_complete(cx)) {
+            return Poll::Ready(Err(error));
+        }
+        Poll::Ready(self.last_write_err.take().map_or(Ok(()), Err))
+    }
+
+    fn poll_seek(
+        &mut self,
+        cx: &mut Context<'_>,
+        std: &Arc<std::fs::File>,
+        pos: SeekFrom,
+    ) -> Poll<Result<u64>> {
+        loop {
+            match ready!(self.poll_complete(cx)) {
+                Some(Operation::Seek(result)) => return Poll::Ready(result),
+                Some(Operation::Write(Err(error))) => self.last_write_err = Some(error),
+                _ => {}
+            }
+            let buf = self.buf();
+            let unread = buf.remaining().len() as i64;
+            buf.clear();
+            let pos = match pos {
+                SeekFrom::Current(offset) => SeekFrom::Current(offset - unread),
+                pos => pos,
+            };
+            self.start(std, move |mut file, _| Operation::Seek(file.seek(pos)));
+        }
+    }
+}
+
+impl AsyncRead for File {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize>> {
+        let this = self.get_mut();
+        this.inner.get_mut().unwrap().poll_read(cx, &this.std, buf)
+    }
+}
+
+impl AsyncWrite for File {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize>> {
+        let this = self.get_mut();
+        this.inner.get_mut().unwrap().poll_write(cx, &this.std, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.get_mut().inner.get_mut().unwrap().poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+impl AsyncSeek for File {
+    fn poll_seek(self: Pin<&mut Self>, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let this = self.get_mut();
+        this.inner.get_mut().unwrap().poll_seek(cx, &this.std, pos)
+    }
+}
+
+impl AsRawFd for File {
+    fn as_raw_fd(&self) -> RawFd {
+        self.std.as_raw_fd()
+    }
+}
+
+impl crate::fs::File for File {
+    type OpenOptions = OpenOptions;
+
+    async fn create(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::File::create(path))
+            .await
+            .map(Self::new)
+    }
+    async fn create_new(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::File::create_new(path))
+            .await
+            .map(Self::new)
+    }
+    async fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_owned();
+        asyncify(move || std::fs::File::open(path))
+            .await
+            .map(Self::new)
+    }
+    fn set_len(&self, len: u64) -> impl Future<Output = Result<()>> {
+        self.asyncify(move |file| file.set_len(len))
+    }
+    fn set_permissions(&self, perm: Permissions) -> impl Future<Output = Result<()>> {
+        self.asyncify(move |file| file.set_permissions(perm))
+    }
+    fn sync_all(&self) -> impl Future<Output = Result<()>> {
+        self.asyncify(std::fs::File::sync_all)
+    }
+    fn sync_data(&self) -> impl Future<Output = Result<()>> {
+        self.asyncify(std::fs::File::sync_data)
+    }
+    fn metadata(&self) -> impl Future<Output = Result<Metadata>> {
+        self.asyncify(std::fs::File::metadata)
+    }
+}
+
+/// [`std::fs::OpenOptions`] opening a [`File`].
+#[derive(Clone, Debug)]
+pub struct OpenOptions(std::fs::OpenOptions);
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self(std::fs::OpenOptions::new())
+    }
+}
+
+impl crate::fs::OpenOptions for OpenOptions {
+    type File = File;
+
+    fn new() -> Self {
+        Self::default()
+    }
+    fn read(&mut self, read: bool) -> &mut Self {
+        self.0.read(read);
+        self
+    }
+    fn write(&mut self, write: bool) -> &mut Self {
+        self.0.write(write);
+        self
+    }
+    fn append(&mut self, append: bool) -> &mut Self {
+        self.0.append(append);
+        self
+    }
+    fn truncate(&mut self, truncate: bool) -> &mut Self {
+        self.0.truncate(truncate);
+        self
+    }
+    fn create(&mut self, create: bool) -> &mut Self {
+        self.0.create(create);
+        self
+    }
+    fn create_new(&mut self, create_new: bool) -> &mut Self {
+        self.0.create_new(create_new);
+        self
+    }
+    async fn open(&self, path: impl AsRef<Path>) -> Result<Self::File> {
+        let (options, path) = (self.0.clone(), path.as_ref().to_owned());
+        asyncify(move || options.open(path)).await.map(File::new)
+    }
+}
+
+impl SendFs for crate::runtime::Futures {
+    type File = File;
+    type DirEntry = DirEntry;
+
+    fn canonocalize(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<PathBuf>> + Send {
+        <Self as Fs>::canonocalize(path)
+    }
+    fn copy(
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<u64>> + Send {
+        <Self as Fs>::copy(from, to)
+    }
+    fn create_dir(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send {
+        <Self as Fs>::create_dir(path)
+    }
+    fn create_dir_all(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send {
+        <Self as Fs>::create_dir_all(path)
+    }
+    fn hard_link(
+        target: impl AsRef<Path> + Send,
+        link: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<()>> + Send {
+        <Self as Fs>::hard_link(target, link)
+    }
+    fn metadata(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Metadata>> + Send {
+        <Self as Fs>::metadata(path)
+    }
+    fn read(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Vec<u8>>> + Send {
+        <Self as Fs>::read(path)
+    }
+    fn read_dir(
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<impl Stream<Item = Result<Self::DirEntry>> + Send>> + Send
+    {
+        <Self as Fs>::read_dir(path)
+    }
+    fn read_link(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<PathBuf>> + Send {
+        <Self as Fs>::read_link(path)
+    }
+    fn read_to_string(
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<String>> + Send {
+        <Self as Fs>::read_to_string(path)
+    }
+    fn remove_dir(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send {
+        <Self as Fs>::remove_dir(path)
+    }
+    fn remove_dir_all(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send {
+        <Self as Fs>::remove_dir_all(path)
+    }
+    fn remove_file(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<()>> + Send {
+        <Self as Fs>::remove_file(path)
+    }
+    fn rename(
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<()>> + Send {
+        <Self as Fs>::rename(from, to)
+    }
+    fn set_permissions(
+        path: impl AsRef<Path> + Send,
+        perm: Permissions,
+    ) -> impl Future<Output = Result<()>> + Send {
+        <Self as Fs>::set_permissions(path, perm)
+    }
+    fn symlink_metadata(
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<Metadata>> + Send {
+        <Self as Fs>::symlink_metadata(path)
+    }
+    fn write(
+        path: impl AsRef<Path> + Send,
+        contents: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<()>> + Send {
+        <Self as Fs>::write(path, contents)
+    }
+}
+
+impl SendDirEntry for DirEntry {
+    fn file_name(&self) -> OsString {
+        <Self as crate::fs::DirEntry>::file_name(self)
+    }
+    fn file_type(&self) -> impl Future<Output = Result<FileType>> + Send {
+        <Self as crate::fs::DirEntry>::file_type(self)
+    }
+    fn metadata(&self) -> impl Future<Output = Result<Metadata>> + Send {
+        <Self as crate::fs::DirEntry>::metadata(self)
+    }
+    fn path(&self) -> PathBuf {
+        <Self as crate::fs::DirEntry>::path(self)
+    }
+}
+
+impl SendFile for File {
+    type OpenOptions = OpenOptions;
+
+    fn create(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as crate::fs::File>::create(path)
+    }
+    fn create_new(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as crate::fs::File>::create_new(path)
+    }
+    fn open(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as crate::fs::File>::open(path)
+    }
+    fn set_len(&self, len: u64) -> impl Future<Output = Result<()>> + Send {
+        <Self as crate::fs::File>::set_len(self, len)
+    }
+    fn set_permissions(&self, perm: Permissions) -> impl Future<Output = Result<()>> + Send {
+        <Self as crate::fs::File>::set_permissions(self, perm)
+    }
+    fn sync_all(&self) -> impl Future<Output = Result<()>> + Send {
+        <Self as crate::fs::File>::sync_all(self)
+    }
+    fn sync_data(&self) -> impl Future<Output = Result<()>> + Send {
+        <Self as crate::fs::File>::sync_data(self)
+    }
+    fn metadata(&self) -> impl Future<Output = Result<Metadata>> + Send {
+        <Self as crate::fs::File>::metadata(self)
+    }
+}
+
+impl SendOpenOptions for OpenOptions {
+    type File = File;
+
+    fn new() -> Self {
+        <Self as crate::fs::OpenOptions>::new()
+    }
+    fn read(&mut self, read: bool) -> &mut Self {
+        <Self as crate::fs::OpenOptions>::read(self, read)
+    }
+    fn write(&mut self, write: bool) -> &mut Self {
+        <Self as crate::fs::OpenOptions>::write(self, write)
+    }
+    fn append(&mut self, append: bool) -> &mut Self {
+        <Self as crate::fs::OpenOptions>::append(self, append)
+    }
+    fn truncate(&mut self, truncate: bool) -> &mut Self {
+        <Self as crate::fs::OpenOptions>::truncate(self, truncate)
+    }
+    fn create(&mut self, create: bool) -> &mut Self {
+        <Self as crate::fs::OpenOptions>::create(self, create)
+    }
+    fn create_new(&mut self, create_new: bool) -> &mut Self {
+        <Self as crate::fs::OpenOptions>::create_new(self, create_new)
+    }
+    fn open(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<Self::File>> + Send {
+        <Self as crate::fs::OpenOptions>::open(self, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{File, OpenOptions};
+    use crate::fs::OpenOptions as _;
+    use futures_lite::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, future::block_on};
+    use std::{
+        io::SeekFrom,
+        path::PathBuf,
+        time::{Duration, Instant},
+    };
+
+    /// Opens a new file in the temporary directory for reading and writing.
+    async fn open(name: &str) -> (File, PathBuf) {
+        let path = std::env::temp_dir().join(format!("byor-{}-{name}", std::process::id()));
+        let file = OpenOptions::default()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .await
+            .unwrap();
+        (file, path)
+    }
+
+    #[test]
+    fn read_after_write() {
+        block_on(async {
+            let (mut file, path) = open("read-after-write").await;
+            file.write_all(b"hello world").await.unwrap();
+            file.seek(SeekFrom::Start(0)).await.unwrap();
+
+            // Reading buffers the rest of the file ahead of the cursor, which the write has to
+            // account for.
+            let mut hello = [0; 5];
+            file.read_exact(&mut hello).await.unwrap();
+            assert_eq!(&hello, b"hello");
+            file.write_all(b"!!").await.unwrap();
+
+            let mut rest = String::new();
+            file.read_to_string(&mut rest).await.unwrap();
+            assert_eq!(rest, "orld");
+            file.seek(SeekFrom::Start(0)).await.unwrap();
+            let mut contents = String::new();
+            file.read_to_string(&mut contents).await.unwrap();
+            assert_eq!(contents, "hello!!orld");
+
+            std::fs::remove_file(path).unwrap();
+        });
+    }
+
+    #[test]
+    fn seek_with_pending_write() {
+        block_on(async {
+            let (mut file, path) = open("seek-with-pending-write").await;
+            file.write_all(b"abc").await.unwrap();
+            assert_eq!(file.seek(SeekFrom::End(0)).await.unwrap(), 3);
+            assert_eq!(file.seek(SeekFrom::Current(-1)).await.unwrap(), 2);
+            let mut c = String::new();
+            file.read_to_string(&mut c).await.unwrap();
+            assert_eq!(c, "c");
+
+            std::fs::remove_file(path).unwrap();
+        });
+    }
+
+    #[test]
+    fn flush_on_drop() {
+        let path = block_on(async {
+            let (mut file, path) = open("flush-on-drop").await;
+            file.write_all(b"written in the background").await.unwrap();
+            path
+        });
+
+        // The write keeps running on the blocking pool after the file is dropped.
+        let start = Instant::now();
+        while std::fs::read(&path).unwrap() != b"written in the background" {
+            assert!(start.elapsed() < Duration::from_secs(5), "write was lost");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+}
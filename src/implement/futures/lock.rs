@@ -1,5 +1,5 @@
 use crate::{
-    lock::{Mutex, RuntimeLock},
+    lock::{Mutex, RuntimeLock, SendMutex, SendRuntimeLock},
     runtime::Futures,
 };
 
@@ -36,6 +36,43 @@ impl<T: ?Sized> Mutex<T> for futures::lock::Mutex<T> {
     }
 }
 
+impl<T: ?Sized + Send> SendMutex<T> for futures::lock::Mutex<T> {
+    type Guard<'a>
+        = <Self as Mutex<T>>::Guard<'a>
+    where
+        Self: 'a;
+
+    fn new(t: T) -> Self
+    where
+        T: Sized,
+    {
+        <Self as Mutex<T>>::new(t)
+    }
+
+    fn lock(&self) -> impl Future<Output = Self::Guard<'_>> + Send {
+        <Self as Mutex<T>>::lock(self)
+    }
+
+    fn try_lock(&self) -> Option<Self::Guard<'_>> {
+        <Self as Mutex<T>>::try_lock(self)
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        <Self as Mutex<T>>::get_mut(self)
+    }
+
+    fn into_inner(self) -> T
+    where
+        T: Sized,
+    {
+        <Self as Mutex<T>>::into_inner(self)
+    }
+}
+
 impl RuntimeLock for Futures {
     type Mutex<T: ?Sized> = futures::lock::Mutex<T>;
 }
+
+impl SendRuntimeLock for Futures {
+    type Mutex<T: ?Sized + Send> = futures::lock::Mutex<T>;
+}
@@ -6,3 +6,29 @@ mod channel;
 
 #[cfg(any(feature = "exec", feature = "local-exec"))]
 mod executor;
+
+#[cfg(any(
+    feature = "exec",
+    feature = "local-exec",
+    feature = "fs",
+    feature = "net"
+))]
+mod blocking;
+
+#[cfg(feature = "task")]
+mod task;
+
+#[cfg(feature = "fs")]
+mod fs;
+
+#[cfg(feature = "time")]
+mod time;
+
+#[cfg(feature = "net")]
+mod net;
+
+#[cfg(feature = "process")]
+mod process;
+
+#[cfg(feature = "signal")]
+mod signal;
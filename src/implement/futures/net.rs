@@ -0,0 +1,606 @@
+//! Networking driven by the reactor of [`async_io`], which runs on a thread of its own, since
+//! `futures` doesn't provide one.
+
+use super::blocking::asyncify;
+use crate::{net::*, runtime::Futures};
+use async_io::Async;
+use std::{
+    io::{self, Result},
+    net::{self, Ipv4Addr, Ipv6Addr, SocketAddr},
+    os::unix::net::{self as unix, SocketAddr as UnixSocketAddr},
+    path::Path,
+};
+
+fn no_addrs() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "could not resolve to any addresses",
+    )
+}
+
+/// Calls `f` with each address `addr` resolves to until it succeeds, returning the last error if
+/// none do.
+async fn each_addr<T, F: Future<Output = Result<T>>>(
+    addr: impl ToSocketAddrs,
+    mut f: impl FnMut(SocketAddr) -> F,
+) -> Result<T> {
+    let mut last_err = None;
+    for addr in <Futures as RuntimeNet>::get_socket_addrs(addr.kind()).await? {
+        match f(addr).await {
+            Ok(output) => return Ok(output),
+            Err(error) => last_err = Some(error),
+        }
+    }
+    Err(last_err.unwrap_or_else(no_addrs))
+}
+
+impl TcpStream for Async<net::TcpStream> {
+    fn connect(addr: impl ToSocketAddrs) -> impl Future<Output = Result<Self>> {
+        each_addr(addr, Async::<net::TcpStream>::connect)
+    }
+    fn local_addr(&self) -> Result<SocketAddr> {
+        self.get_ref().local_addr()
+    }
+    fn nodelay(&self) -> Result<bool> {
+        self.get_ref().nodelay()
+    }
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> {
+        self.peek(buf)
+    }
+    fn peer_addr(&self) -> Result<SocketAddr> {
+        self.get_ref().peer_addr()
+    }
+    fn set_nodelay(&self, nodelay: bool) -> Result<()> {
+        self.get_ref().set_nodelay(nodelay)
+    }
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        self.get_ref().set_ttl(ttl)
+    }
+    fn ttl(&self) -> Result<u32> {
+        self.get_ref().ttl()
+    }
+}
+
+impl TcpListener for Async<net::TcpListener> {
+    type Stream = Async<net::TcpStream>;
+
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, SocketAddr)>> {
+        self.accept()
+    }
+    fn bind(addr: impl ToSocketAddrs) -> impl Future<Output = Result<Self>> {
+        each_addr(addr, |addr| {
+            std::future::ready(Async::<net::TcpListener>::bind(addr))
+        })
+    }
+    fn local_addr(&self) -> Result<SocketAddr> {
+        self.get_ref().local_addr()
+    }
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        self.get_ref().set_ttl(ttl)
+    }
+    fn ttl(&self) -> Result<u32> {
+        self.get_ref().ttl()
+    }
+}
+
+impl UdpSocket for Async<net::UdpSocket> {
+    fn bind(addr: impl ToSocketAddrs) -> impl Future<Output = Result<Self>> {
+        each_addr(addr, |addr| {
+            std::future::ready(Async::<net::UdpSocket>::bind(addr))
+        })
+    }
+    fn broadcast(&self) -> Result<bool> {
+        self.get_ref().broadcast()
+    }
+    async fn connect(&self, addr: impl ToSocketAddrs) -> Result<()> {
+        self.get_ref().connect(
+            <Futures as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+    }
+    fn join_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
+        self.get_ref().join_multicast_v4(&multiaddr, &interface)
+    }
+    fn join_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()> {
+        self.get_ref().join_multicast_v6(multiaddr, interface)
+    }
+    fn leave_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
+        self.get_ref().leave_multicast_v4(&multiaddr, &interface)
+    }
+    fn leave_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()> {
+        self.get_ref().leave_multicast_v6(multiaddr, interface)
+    }
+    fn local_addr(&self) -> Result<SocketAddr> {
+        self.get_ref().local_addr()
+    }
+    fn multicast_loop_v4(&self) -> Result<bool> {
+        self.get_ref().multicast_loop_v4()
+    }
+    fn multicast_loop_v6(&self) -> Result<bool> {
+        self.get_ref().multicast_loop_v6()
+    }
+    fn multicast_ttl_v4(&self) -> Result<u32> {
+        self.get_ref().multicast_ttl_v4()
+    }
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> {
+        self.peek(buf)
+    }
+    fn peek_from(&self, buf: &mut [u8]) -> impl Future<Output = Result<(usize, SocketAddr)>> {
+        self.peek_from(buf)
+    }
+    fn peer_addr(&self) -> Result<SocketAddr> {
+        self.get_ref().peer_addr()
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> {
+        self.recv(buf)
+    }
+    fn recv_from(&self, buf: &mut [u8]) -> impl Future<Output = Result<(usize, SocketAddr)>> {
+        self.recv_from(buf)
+    }
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>> {
+        self.send(buf)
+    }
+    async fn send_to(&self, buf: &[u8], addr: impl ToSocketAddrs) -> Result<usize> {
+        let addrs = <Futures as RuntimeNet>::get_socket_addrs(addr.kind()).await?;
+        self.send_to(buf, *addrs.first().ok_or_else(no_addrs)?)
+            .await
+    }
+    fn set_broadcast(&self, on: bool) -> Result<()> {
+        self.get_ref().set_broadcast(on)
+    }
+    fn set_multicast_loop_v4(&self, on: bool) -> Result<()> {
+        self.get_ref().set_multicast_loop_v4(on)
+    }
+    fn set_multicast_loop_v6(&self, on: bool) -> Result<()> {
+        self.get_ref().set_multicast_loop_v6(on)
+    }
+    fn set_multicast_ttl_v4(&self, ttl: u32) -> Result<()> {
+        self.get_ref().set_multicast_ttl_v4(ttl)
+    }
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        self.get_ref().set_ttl(ttl)
+    }
+    fn ttl(&self) -> Result<u32> {
+        self.get_ref().ttl()
+    }
+}
+
+impl UnixStream for Async<unix::UnixStream> {
+    fn connect(path: impl AsRef<Path>) -> impl Future<Output = Result<Self>> {
+        Async::<unix::UnixStream>::connect(path)
+    }
+    async fn connect_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        let addr = addr.clone();
+        Async::new(asyncify(move || unix::UnixStream::connect_addr(&addr)).await?)
+    }
+    fn pair() -> Result<(Self, Self)> {
+        Async::<unix::UnixStream>::pair()
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        self.get_ref().local_addr()
+    }
+    fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        self.get_ref().peer_addr()
+    }
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn peer_cred(&self) -> Result<UCred> {
+        let cred = rustix::net::sockopt::socket_peercred(self)?;
+        Ok(UCred {
+            uid: cred.uid.as_raw(),
+            gid: cred.gid.as_raw(),
+            pid: Some(cred.pid.as_raw_nonzero().get()),
+        })
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn peer_cred(&self) -> Result<UCred> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+impl UnixListener for Async<unix::UnixListener> {
+    type Stream = Async<unix::UnixStream>;
+
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, UnixSocketAddr)>> {
+        self.accept()
+    }
+    fn bind(path: impl AsRef<Path>) -> Result<Self> {
+        Async::<unix::UnixListener>::bind(path)
+    }
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        Async::new(unix::UnixListener::bind_addr(addr)?)
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        self.get_ref().local_addr()
+    }
+}
+
+impl UnixDatagram for Async<unix::UnixDatagram> {
+    fn bind(path: impl AsRef<Path>) -> Result<Self> {
+        Async::<unix::UnixDatagram>::bind(path)
+    }
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        Async::new(unix::UnixDatagram::bind_addr(addr)?)
+    }
+    fn unbound() -> Result<Self> {
+        Async::<unix::UnixDatagram>::unbound()
+    }
+    fn pair() -> Result<(Self, Self)> {
+        Async::<unix::UnixDatagram>::pair()
+    }
+    fn connect(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.get_ref().connect(path)
+    }
+    fn connect_addr(&self, addr: &UnixSocketAddr) -> Result<()> {
+        self.get_ref().connect_addr(addr)
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        self.get_ref().local_addr()
+    }
+    fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        self.get_ref().peer_addr()
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> {
+        self.recv(buf)
+    }
+    fn recv_from(&self, buf: &mut [u8]) -> impl Future<Output = Result<(usize, UnixSocketAddr)>> {
+        self.recv_from(buf)
+    }
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>> {
+        self.send(buf)
+    }
+    fn send_to(&self, buf: &[u8], path: impl AsRef<Path>) -> impl Future<Output = Result<usize>> {
+        self.send_to(buf, path.as_ref().to_owned())
+    }
+}
+
+impl RuntimeNet for Futures {
+    type TcpStream = Async<net::TcpStream>;
+    type TcpListener = Async<net::TcpListener>;
+    type UdpSocket = Async<net::UdpSocket>;
+    type UnixStream = Async<unix::UnixStream>;
+    type UnixListener = Async<unix::UnixListener>;
+    type UnixDatagram = Async<unix::UnixDatagram>;
+
+    async fn get_socket_addrs(kind: SocketAddrsKind<'_>) -> Result<Vec<SocketAddr>> {
+        use std::net::ToSocketAddrs;
+        // Only host names have to be looked up, which blocks, so everything else is resolved here.
+        match kind {
+            SocketAddrsKind::Slice(addrs) => Ok(addrs.to_vec()),
+            SocketAddrsKind::StrPort(host, port) => match host.parse::<net::IpAddr>() {
+                Ok(addr) => Ok(vec![SocketAddr::new(addr, port)]),
+                Err(_) => {
+                    let host = host.to_owned();
+                    asyncify(move || Ok((host.as_str(), port).to_socket_addrs()?.collect())).await
+                }
+            },
+            SocketAddrsKind::IpAddr(addr, port) => Ok(vec![SocketAddr::new(addr, port)]),
+            SocketAddrsKind::Ipv4Addr(addr, port) => Ok(vec![SocketAddr::from((addr, port))]),
+            SocketAddrsKind::Ipv6Addr(addr, port) => Ok(vec![SocketAddr::from((addr, port))]),
+            SocketAddrsKind::Str(addr) => match addr.parse::<SocketAddr>() {
+                Ok(addr) => Ok(vec![addr]),
+                Err(_) => {
+                    let addr = addr.to_owned();
+                    asyncify(move || Ok(addr.to_socket_addrs()?.collect())).await
+                }
+            },
+            SocketAddrsKind::SocketAddr(addr) => Ok(vec![*addr]),
+            SocketAddrsKind::SocketAddrV4(addr) => Ok(vec![(*addr).into()]),
+            SocketAddrsKind::SocketAddrV6(addr) => Ok(vec![(*addr).into()]),
+        }
+    }
+}
+
+impl SendTcpStream for Async<net::TcpStream> {
+    fn connect(addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as TcpStream>::connect(addr)
+    }
+    fn local_addr(&self) -> Result<SocketAddr> {
+        <Self as TcpStream>::local_addr(self)
+    }
+    fn nodelay(&self) -> Result<bool> {
+        <Self as TcpStream>::nodelay(self)
+    }
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as TcpStream>::peek(self, buf)
+    }
+    fn peer_addr(&self) -> Result<SocketAddr> {
+        <Self as TcpStream>::peer_addr(self)
+    }
+    fn set_nodelay(&self, nodelay: bool) -> Result<()> {
+        <Self as TcpStream>::set_nodelay(self, nodelay)
+    }
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        <Self as TcpStream>::set_ttl(self, ttl)
+    }
+    fn ttl(&self) -> Result<u32> {
+        <Self as TcpStream>::ttl(self)
+    }
+}
+
+impl SendTcpListener for Async<net::TcpListener> {
+    type Stream = Async<net::TcpStream>;
+
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, SocketAddr)>> + Send {
+        <Self as TcpListener>::accept(self)
+    }
+    fn bind(addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as TcpListener>::bind(addr)
+    }
+    fn local_addr(&self) -> Result<SocketAddr> {
+        <Self as TcpListener>::local_addr(self)
+    }
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        <Self as TcpListener>::set_ttl(self, ttl)
+    }
+    fn ttl(&self) -> Result<u32> {
+        <Self as TcpListener>::ttl(self)
+    }
+}
+
+impl SendUdpSocket for Async<net::UdpSocket> {
+    fn bind(addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as UdpSocket>::bind(addr)
+    }
+    fn broadcast(&self) -> Result<bool> {
+        <Self as UdpSocket>::broadcast(self)
+    }
+    fn connect(&self, addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<()>> + Send {
+        <Self as UdpSocket>::connect(self, addr)
+    }
+    fn join_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
+        <Self as UdpSocket>::join_multicast_v4(self, multiaddr, interface)
+    }
+    fn join_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()> {
+        <Self as UdpSocket>::join_multicast_v6(self, multiaddr, interface)
+    }
+    fn leave_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
+        <Self as UdpSocket>::leave_multicast_v4(self, multiaddr, interface)
+    }
+    fn leave_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()> {
+        <Self as UdpSocket>::leave_multicast_v6(self, multiaddr, interface)
+    }
+    fn local_addr(&self) -> Result<SocketAddr> {
+        <Self as UdpSocket>::local_addr(self)
+    }
+    fn multicast_loop_v4(&self) -> Result<bool> {
+        <Self as UdpSocket>::multicast_loop_v4(self)
+    }
+    fn multicast_loop_v6(&self) -> Result<bool> {
+        <Self as UdpSocket>::multicast_loop_v6(self)
+    }
+    fn multicast_ttl_v4(&self) -> Result<u32> {
+        <Self as UdpSocket>::multicast_ttl_v4(self)
+    }
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UdpSocket>::peek(self, buf)
+    }
+    fn peek_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = Result<(usize, SocketAddr)>> + Send {
+        <Self as UdpSocket>::peek_from(self, buf)
+    }
+    fn peer_addr(&self) -> Result<SocketAddr> {
+        <Self as UdpSocket>::peer_addr(self)
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UdpSocket>::recv(self, buf)
+    }
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = Result<(usize, SocketAddr)>> + Send {
+        <Self as UdpSocket>::recv_from(self, buf)
+    }
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UdpSocket>::send(self, buf)
+    }
+    fn send_to(
+        &self,
+        buf: &[u8],
+        addr: impl ToSocketAddrs + Send,
+    ) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UdpSocket>::send_to(self, buf, addr)
+    }
+    fn set_broadcast(&self, on: bool) -> Result<()> {
+        <Self as UdpSocket>::set_broadcast(self, on)
+    }
+    fn set_multicast_loop_v4(&self, on: bool) -> Result<()> {
+        <Self as UdpSocket>::set_multicast_loop_v4(self, on)
+    }
+    fn set_multicast_loop_v6(&self, on: bool) -> Result<()> {
+        <Self as UdpSocket>::set_multicast_loop_v6(self, on)
+    }
+    fn set_multicast_ttl_v4(&self, ttl: u32) -> Result<()> {
+        <Self as UdpSocket>::set_multicast_ttl_v4(self, ttl)
+    }
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        <Self as UdpSocket>::set_ttl(self, ttl)
+    }
+    fn ttl(&self) -> Result<u32> {
+        <Self as UdpSocket>::ttl(self)
+    }
+}
+
+impl SendUnixStream for Async<unix::UnixStream> {
+    fn connect(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as UnixStream>::connect(path)
+    }
+    fn connect_addr(addr: &UnixSocketAddr) -> impl Future<Output = Result<Self>> + Send {
+        <Self as UnixStream>::connect_addr(addr)
+    }
+    fn pair() -> Result<(Self, Self)> {
+        <Self as UnixStream>::pair()
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixStream>::local_addr(self)
+    }
+    fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixStream>::peer_addr(self)
+    }
+    fn peer_cred(&self) -> Result<UCred> {
+        <Self as UnixStream>::peer_cred(self)
+    }
+}
+
+impl SendUnixListener for Async<unix::UnixListener> {
+    type Stream = Async<unix::UnixStream>;
+
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, UnixSocketAddr)>> + Send {
+        <Self as UnixListener>::accept(self)
+    }
+    fn bind(path: impl AsRef<Path>) -> Result<Self> {
+        <Self as UnixListener>::bind(path)
+    }
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        <Self as UnixListener>::bind_addr(addr)
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixListener>::local_addr(self)
+    }
+}
+
+impl SendUnixDatagram for Async<unix::UnixDatagram> {
+    fn bind(path: impl AsRef<Path>) -> Result<Self> {
+        <Self as UnixDatagram>::bind(path)
+    }
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        <Self as UnixDatagram>::bind_addr(addr)
+    }
+    fn unbound() -> Result<Self> {
+        <Self as UnixDatagram>::unbound()
+    }
+    fn pair() -> Result<(Self, Self)> {
+        <Self as UnixDatagram>::pair()
+    }
+    fn connect(&self, path: impl AsRef<Path>) -> Result<()> {
+        <Self as UnixDatagram>::connect(self, path)
+    }
+    fn connect_addr(&self, addr: &UnixSocketAddr) -> Result<()> {
+        <Self as UnixDatagram>::connect_addr(self, addr)
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixDatagram>::local_addr(self)
+    }
+    fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixDatagram>::peer_addr(self)
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UnixDatagram>::recv(self, buf)
+    }
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = Result<(usize, UnixSocketAddr)>> + Send {
+        <Self as UnixDatagram>::recv_from(self, buf)
+    }
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UnixDatagram>::send(self, buf)
+    }
+    fn send_to(
+        &self,
+        buf: &[u8],
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UnixDatagram>::send_to(self, buf, path)
+    }
+}
+
+impl SendRuntimeNet for Futures {
+    type TcpStream = Async<net::TcpStream>;
+    type TcpListener = Async<net::TcpListener>;
+    type UdpSocket = Async<net::UdpSocket>;
+    type UnixStream = Async<unix::UnixStream>;
+    type UnixListener = Async<unix::UnixListener>;
+    type UnixDatagram = Async<unix::UnixDatagram>;
+
+    fn get_socket_addrs(
+        kind: SocketAddrsKind<'_>,
+    ) -> impl Future<Output = Result<Vec<SocketAddr>>> + Send {
+        <Self as RuntimeNet>::get_socket_addrs(kind)
+    }
+}
+
+#[cfg(all(test, any(feature = "exec", feature = "local-exec")))]
+mod tests {
+    use crate::{
+        net::{RuntimeNet, TcpListener, TcpStream, UdpSocket, UnixStream},
+        runtime::Futures,
+    };
+    use futures::executor::block_on;
+    use futures_lite::{AsyncReadExt, AsyncWriteExt, future::zip};
+    use std::net::Ipv4Addr;
+
+    // `Async` has inherent methods of the same names, so the traits are called explicitly.
+    type Listener = <Futures as RuntimeNet>::TcpListener;
+    type Stream = <Futures as RuntimeNet>::TcpStream;
+    type Socket = <Futures as RuntimeNet>::UdpSocket;
+    type Unix = <Futures as RuntimeNet>::UnixStream;
+
+    #[test]
+    fn tcp_echo() {
+        block_on(async {
+            let listener = <Listener as TcpListener>::bind((Ipv4Addr::LOCALHOST, 0))
+                .await
+                .unwrap();
+            let addr = TcpListener::local_addr(&listener).unwrap();
+            let server = async {
+                let (mut stream, peer) = TcpListener::accept(&listener).await.unwrap();
+                assert_eq!(TcpStream::peer_addr(&stream).unwrap(), peer);
+                let mut buf = [0; 5];
+                stream.read_exact(&mut buf).await.unwrap();
+                stream.write_all(&buf).await.unwrap();
+            };
+            let client = async {
+                let mut stream = <Stream as TcpStream>::connect(addr).await.unwrap();
+                assert_eq!(TcpStream::peer_addr(&stream).unwrap(), addr);
+                stream.write_all(b"hello").await.unwrap();
+                let mut buf = [0; 5];
+                stream.read_exact(&mut buf).await.unwrap();
+                buf
+            };
+            let ((), echoed) = zip(server, client).await;
+            assert_eq!(&echoed, b"hello");
+        });
+    }
+
+    #[test]
+    fn udp_round_trip() {
+        block_on(async {
+            let a = <Socket as UdpSocket>::bind((Ipv4Addr::LOCALHOST, 0))
+                .await
+                .unwrap();
+            let b = <Socket as UdpSocket>::bind((Ipv4Addr::LOCALHOST, 0))
+                .await
+                .unwrap();
+            let a_addr = UdpSocket::local_addr(&a).unwrap();
+            let b_addr = UdpSocket::local_addr(&b).unwrap();
+
+            let mut buf = [0; 16];
+            assert_eq!(UdpSocket::send_to(&a, b"ping", b_addr).await.unwrap(), 4);
+            let (len, from) = UdpSocket::recv_from(&b, &mut buf).await.unwrap();
+            assert_eq!((&buf[..len], from), (&b"ping"[..], a_addr));
+
+            assert_eq!(UdpSocket::send_to(&b, b"pong", from).await.unwrap(), 4);
+            let (len, from) = UdpSocket::recv_from(&a, &mut buf).await.unwrap();
+            assert_eq!((&buf[..len], from), (&b"pong"[..], b_addr));
+        });
+    }
+
+    #[test]
+    fn unix_stream_pair() {
+        block_on(async {
+            let (mut a, mut b) = <Unix as UnixStream>::pair().unwrap();
+            a.write_all(b"hello").await.unwrap();
+            let mut buf = [0; 5];
+            b.read_exact(&mut buf).await.unwrap();
+            assert_eq!(&buf, b"hello");
+
+            drop(a);
+            let mut rest = Vec::new();
+            b.read_to_end(&mut rest).await.unwrap();
+            assert!(rest.is_empty());
+        });
+    }
+}
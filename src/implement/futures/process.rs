@@ -0,0 +1,11 @@
+use crate::{process::*, runtime::Futures};
+
+impl RuntimeProcess for Futures {
+    type Command = async_process::Command;
+    type Child = async_process::Child;
+}
+
+impl SendRuntimeProcess for Futures {
+    type Command = async_process::Command;
+    type Child = async_process::Child;
+}
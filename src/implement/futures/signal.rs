@@ -0,0 +1,28 @@
+use crate::{
+    implement::signal::{self, Signals},
+    runtime::Futures,
+    signal::*,
+};
+use std::io::Result;
+
+impl RuntimeSignal for Futures {
+    type Signal = Signals;
+
+    fn ctrl_c() -> impl Future<Output = Result<()>> {
+        signal::ctrl_c()
+    }
+    fn signal(kind: SignalKind) -> Result<Self::Signal> {
+        signal::signal(kind)
+    }
+}
+
+impl SendRuntimeSignal for Futures {
+    type Signal = Signals;
+
+    fn ctrl_c() -> impl Future<Output = Result<()>> + Send {
+        <Self as RuntimeSignal>::ctrl_c()
+    }
+    fn signal(kind: SignalKind) -> Result<Self::Signal> {
+        <Self as RuntimeSignal>::signal(kind)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{runtime::Futures, task::*};
+
+impl RuntimeTask for Futures {
+    fn yield_now() -> impl Future<Output = ()> {
+        futures_lite::future::yield_now()
+    }
+
+    fn consume_budget() -> impl Future<Output = ()> {
+        crate::task::consume_budget(std::future::ready(()))
+    }
+}
+
+impl SendRuntimeTask for Futures {
+    fn yield_now() -> impl Future<Output = ()> + Send {
+        <Self as RuntimeTask>::yield_now()
+    }
+
+    fn consume_budget() -> impl Future<Output = ()> + Send {
+        <Self as RuntimeTask>::consume_budget()
+    }
+}
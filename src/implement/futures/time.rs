@@ -0,0 +1,224 @@
+//! Timers driven by a dedicated thread, since `futures` doesn't provide any.
+
+use crate::{
+    implement::interval::{self, Interval, add},
+    runtime::Futures,
+    time::{self, SendTime, Time},
+};
+use std::{
+    collections::BTreeMap,
+    pin::Pin,
+    sync::{Condvar, LazyLock, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+/// The timers of every pending [`Sleep`], woken by a thread spawned on first use.
+static TIMERS: LazyLock<Timers> = LazyLock::new(|| {
+    std::thread::Builder::new()
+        .name("byor-timer".into())
+        .spawn(|| TIMERS.run())
+        .expect("failed to spawn the timer thread");
+    Timers::default()
+});
+
+#[derive(Default)]
+struct Timers {
+    state: Mutex<State>,
+    /// Notified when a timer is registered with an earlier deadline than every other.
+    condvar: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    /// The wakers of pending sleeps, by deadline and id.
+    wakers: BTreeMap<(Instant, u64), Waker>,
+    next_id: u64,
+}
+
+impl Timers {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+
+    /// Registers `waker` to be woken at the deadline of `key`.
+    fn insert(&self, mut state: MutexGuard<'_, State>, key: (Instant, u64), waker: Waker) {
+        let earliest = state
+            .wakers
+            .first_key_value()
+            .is_none_or(|(first, _)| key < *first);
+        state.wakers.insert(key, waker);
+        drop(state);
+        if earliest {
+            self.condvar.notify_one();
+        }
+    }
+
+    fn run(&self) {
+        let mut state = self.lock();
+        loop {
+            let now = Instant::now();
+            let pending = state.wakers.split_off(&(now, u64::MAX));
+            let expired = std::mem::replace(&mut state.wakers, pending);
+            if !expired.is_empty() {
+                drop(state);
+                expired.into_values().for_each(Waker::wake);
+                state = self.lock();
+                continue;
+            }
+            state = match state.wakers.first_key_value() {
+                Some(((deadline, _), _)) => {
+                    let timeout = deadline.saturating_duration_since(now);
+                    self.condvar.wait_timeout(state, timeout).unwrap().0
+                }
+                None => self.condvar.wait(state).unwrap(),
+            };
+        }
+    }
+}
+
+pub struct Sleep {
+    deadline: Instant,
+    id: u64,
+}
+
+impl Sleep {
+    fn new(deadline: Instant) -> Self {
+        let id = {
+            let mut state = TIMERS.lock();
+            state.next_id += 1;
+            state.next_id
+        };
+        Self { deadline, id }
+    }
+
+    fn key(&self) -> (Instant, u64) {
+        (self.deadline, self.id)
+    }
+}
+
+impl Future for Sleep {
+    type Output = Instant;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let now = Instant::now();
+        let mut state = TIMERS.lock();
+        if self.deadline <= now {
+            state.wakers.remove(&self.key());
+            return Poll::Ready(self.deadline);
+        }
+        match state.wakers.get(&self.key()) {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            _ => TIMERS.insert(state, self.key(), cx.waker().clone()),
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        TIMERS.lock().wakers.remove(&self.key());
+    }
+}
+
+impl time::Sleep for Sleep {
+    fn reset(&mut self, deadline: Instant) {
+        let mut state = TIMERS.lock();
+        let waker = state.wakers.remove(&self.key());
+        self.deadline = deadline;
+        // Keep waiting with the same waker, as if the sleep had been polled again.
+        if let Some(waker) = waker {
+            if deadline <= Instant::now() {
+                drop(state);
+                waker.wake();
+            } else {
+                TIMERS.insert(state, self.key(), waker);
+            }
+        }
+    }
+
+    fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    fn is_elapsed(&self) -> bool {
+        self.deadline <= Instant::now()
+    }
+}
+
+impl interval::Timer for Sleep {
+    fn at(deadline: Instant) -> Self {
+        Self::new(deadline)
+    }
+
+    fn set_at(&mut self, deadline: Instant) {
+        time::Sleep::reset(self, deadline);
+    }
+}
+
+impl Time for Futures {
+    type Sleep = Sleep;
+
+    fn sleep(duration: Duration) -> Self::Sleep {
+        Sleep::new(add(Instant::now(), duration))
+    }
+    fn sleep_until(deadline: Instant) -> Self::Sleep {
+        Sleep::new(deadline)
+    }
+    fn interval(period: Duration) -> impl time::Interval {
+        Interval::<Sleep>::new(Instant::now(), period)
+    }
+    fn interval_at(start: Instant, period: Duration) -> impl time::Interval {
+        Interval::<Sleep>::new(start, period)
+    }
+}
+
+impl SendTime for Futures {
+    type Sleep = Sleep;
+
+    fn sleep(duration: Duration) -> Self::Sleep {
+        <Self as Time>::sleep(duration)
+    }
+    fn sleep_until(deadline: Instant) -> Self::Sleep {
+        <Self as Time>::sleep_until(deadline)
+    }
+    fn interval(period: Duration) -> impl time::Interval + Send {
+        <Self as Time>::interval(period)
+    }
+    fn interval_at(start: Instant, period: Duration) -> impl time::Interval + Send {
+        <Self as Time>::interval_at(start, period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        runtime::Futures,
+        time::{Sleep as _, Time},
+    };
+    use futures_lite::future::{block_on, poll_once};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn sleep_resolves_to_its_deadline() {
+        let start = Instant::now();
+        let sleep = <Futures as Time>::sleep(Duration::from_millis(20));
+        let deadline = sleep.deadline();
+        assert!(deadline >= start + Duration::from_millis(20));
+        assert_eq!(block_on(sleep), deadline);
+        assert!(Instant::now() >= deadline);
+    }
+
+    #[test]
+    fn reset_to_an_earlier_deadline() {
+        let start = Instant::now();
+        let mut sleep = <Futures as Time>::sleep(Duration::from_secs(60));
+        block_on(async {
+            assert!(poll_once(&mut sleep).await.is_none());
+            let deadline = Instant::now() + Duration::from_millis(20);
+            sleep.reset(deadline);
+            assert_eq!((&mut sleep).await, deadline);
+        });
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,231 @@
+//! An [`Interval`](crate::time::Interval) for runtimes whose timers can be rescheduled, but which
+//! don't have an interval type of their own with the same semantics.
+
+use crate::time::MissedTickBehavior;
+use futures_lite::Stream;
+use std::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+    time::{Duration, Instant},
+};
+
+/// How late a tick has to be yielded for it to be considered missed.
+const MISSED_TICK_THRESHOLD: Duration = Duration::from_millis(5);
+
+/// A timer which completes at a deadline that can be changed after it was created.
+pub trait Timer: Future<Output = Instant> + Unpin {
+    fn at(deadline: Instant) -> Self;
+
+    fn set_at(&mut self, deadline: Instant);
+
+    /// Returns the current time of the clock the timer is driven by.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+pub struct Interval<T> {
+    timer: T,
+    /// When the next tick is scheduled.
+    deadline: Instant,
+    period: Duration,
+    missed_tick_behavior: MissedTickBehavior,
+}
+
+impl<T: Timer> Interval<T> {
+    pub fn new(start: Instant, period: Duration) -> Self {
+        assert!(!period.is_zero(), "`period` must be non-zero");
+        Self {
+            timer: T::at(start),
+            deadline: start,
+            period,
+            missed_tick_behavior: MissedTickBehavior::default(),
+        }
+    }
+
+    fn schedule(&mut self, deadline: Instant) {
+        self.deadline = deadline;
+        self.timer.set_at(deadline);
+    }
+
+    /// Returns when the tick after one scheduled at `tick` is due, given that it's now `now`.
+    fn next_deadline(&self, tick: Instant, now: Instant) -> Instant {
+        if now <= tick + MISSED_TICK_THRESHOLD {
+            return add(tick, self.period);
+        }
+        match self.missed_tick_behavior {
+            MissedTickBehavior::Burst => add(tick, self.period),
+            MissedTickBehavior::Delay => add(now, self.period),
+            MissedTickBehavior::Skip => {
+                let late = (now - tick).as_nanos() % self.period.as_nanos();
+                add(now, self.period - Duration::from_nanos(late as u64))
+            }
+        }
+    }
+}
+
+/// Adds `duration` to `instant`, saturating to a deadline that is never reached in practice.
+pub fn add(instant: Instant, duration: Duration) -> Instant {
+    instant
+        .checked_add(duration)
+        .unwrap_or_else(|| instant + Duration::from_secs(60 * 60 * 24 * 365 * 30))
+}
+
+impl<T: Timer> Stream for Interval<T> {
+    type Item = Instant;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        ready!(Pin::new(&mut this.timer).poll(cx));
+        let tick = this.deadline;
+        let next = this.next_deadline(tick, this.timer.now());
+        this.schedule(next);
+        Poll::Ready(Some(tick))
+    }
+}
+
+impl<T: Timer> crate::time::Interval for Interval<T> {
+    fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = behavior;
+    }
+
+    fn reset(&mut self) {
+        self.schedule(add(self.timer.now(), self.period));
+    }
+
+    fn reset_at(&mut self, deadline: Instant) {
+        self.schedule(deadline);
+    }
+
+    fn period(&self) -> Duration {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::add;
+    use crate::time::{Interval, MissedTickBehavior, MockClock, Time};
+    use std::{
+        pin::Pin,
+        task::{Context, Poll, Waker},
+        time::{Duration, Instant},
+    };
+
+    const PERIOD: Duration = Duration::from_secs(1);
+
+    fn poll_tick(interval: &mut impl Interval) -> Poll<Instant> {
+        let mut cx = Context::from_waker(Waker::noop());
+        Pin::new(interval)
+            .poll_next(&mut cx)
+            .map(|tick| tick.unwrap())
+    }
+
+    /// Advances the clock in small steps until the interval ticks, returning the tick.
+    fn next_tick(interval: &mut impl Interval) -> Instant {
+        loop {
+            if let Poll::Ready(tick) = poll_tick(interval) {
+                return tick;
+            }
+            MockClock::advance(Duration::from_millis(100));
+        }
+    }
+
+    /// Misses three and a half periods after the first tick, returning when the following ticks
+    /// were due, in milliseconds from the start.
+    fn ticks_after_missing(behavior: MissedTickBehavior) -> Vec<u128> {
+        let start = MockClock::now();
+        let mut interval = <MockClock as Time>::interval_at(start, PERIOD);
+        interval.set_missed_tick_behavior(behavior);
+        assert_eq!(poll_tick(&mut interval), Poll::Ready(start));
+        MockClock::advance(Duration::from_millis(3500));
+        (0..5)
+            .map(|_| (next_tick(&mut interval) - start).as_millis())
+            .collect()
+    }
+
+    #[test]
+    fn burst_yields_every_missed_tick() {
+        assert_eq!(
+            ticks_after_missing(MissedTickBehavior::Burst),
+            [1000, 2000, 3000, 4000, 5000]
+        );
+    }
+
+    #[test]
+    fn delay_reschedules_from_the_late_tick() {
+        assert_eq!(
+            ticks_after_missing(MissedTickBehavior::Delay),
+            [1000, 4500, 5500, 6500, 7500]
+        );
+    }
+
+    #[test]
+    fn skip_stays_on_schedule() {
+        assert_eq!(
+            ticks_after_missing(MissedTickBehavior::Skip),
+            [1000, 4000, 5000, 6000, 7000]
+        );
+    }
+
+    #[test]
+    fn slightly_late_ticks_are_not_missed() {
+        let start = MockClock::now();
+        let mut interval = <MockClock as Time>::interval_at(start, PERIOD);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        assert_eq!(poll_tick(&mut interval), Poll::Ready(start));
+
+        // Within the threshold, the next tick stays on schedule.
+        MockClock::advance(Duration::from_millis(1003));
+        assert_eq!(poll_tick(&mut interval), Poll::Ready(start + PERIOD));
+        MockClock::advance(Duration::from_millis(997));
+        assert_eq!(poll_tick(&mut interval), Poll::Ready(start + PERIOD * 2));
+
+        // Past it, the tick is missed and the next one is delayed.
+        MockClock::advance(Duration::from_millis(1010));
+        assert_eq!(poll_tick(&mut interval), Poll::Ready(start + PERIOD * 3));
+        MockClock::advance(Duration::from_millis(990));
+        assert!(poll_tick(&mut interval).is_pending());
+        MockClock::advance(Duration::from_millis(10));
+        assert_eq!(
+            poll_tick(&mut interval),
+            Poll::Ready(start + Duration::from_millis(4010))
+        );
+    }
+
+    #[test]
+    fn reset_moves_the_next_tick() {
+        let start = MockClock::now();
+        let mut interval = <MockClock as Time>::interval_at(start, PERIOD);
+        assert_eq!(poll_tick(&mut interval), Poll::Ready(start));
+
+        MockClock::advance(Duration::from_millis(500));
+        interval.reset();
+        MockClock::advance(Duration::from_millis(500));
+        assert!(poll_tick(&mut interval).is_pending());
+        assert_eq!(
+            next_tick(&mut interval),
+            start + Duration::from_millis(1500)
+        );
+
+        let deadline = MockClock::now() + Duration::from_millis(200);
+        interval.reset_at(deadline);
+        assert_eq!(next_tick(&mut interval), deadline);
+        assert_eq!(next_tick(&mut interval), deadline + PERIOD);
+    }
+
+    #[test]
+    fn add_saturates() {
+        let now = Instant::now();
+        assert!(add(now, Duration::MAX) > now + Duration::from_secs(60 * 60 * 24 * 365));
+
+        let mut interval = <MockClock as Time>::interval_at(MockClock::now(), Duration::MAX);
+        assert!(poll_tick(&mut interval).is_ready());
+        MockClock::advance(PERIOD);
+        assert!(poll_tick(&mut interval).is_pending());
+    }
+}
@@ -1,3 +1,25 @@
+#[cfg(any(feature = "exec", feature = "local-exec"))]
+mod abort;
+
+#[cfg(any(
+    feature = "exec",
+    feature = "local-exec",
+    all(feature = "futures", any(feature = "fs", feature = "net"))
+))]
+mod builder;
+
+#[cfg(any(feature = "exec", feature = "local-exec"))]
+mod task_set;
+
+#[cfg(feature = "time")]
+pub(crate) mod interval;
+
+#[cfg(all(feature = "process", any(feature = "smol", feature = "futures")))]
+mod process;
+
+#[cfg(all(feature = "signal", any(feature = "smol", feature = "futures")))]
+mod signal;
+
 #[cfg(feature = "smol")]
 mod smol;
 
@@ -0,0 +1,151 @@
+//! Signal handling on [`async_signal`], which both smol and futures use.
+//!
+//! Dropping an [`async_signal::Signals`] removes its handler, so each kind of signal gets a single
+//! one, registered on first use and read from by a dedicated thread for the rest of the process.
+//! Every stream then shares the notifications it receives.
+
+use crate::signal::*;
+use async_signal::Signal as AsyncSignal;
+use futures_lite::{Stream, StreamExt};
+use std::{
+    collections::BTreeMap,
+    io::{Error, Result},
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+/// The registration of each kind of signal listened for so far.
+static REGISTRY: Mutex<Vec<(SignalKind, Arc<Registration>)>> = Mutex::new(Vec::new());
+
+#[derive(Default)]
+struct Registration {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// The number of notifications received so far.
+    received: u64,
+    /// Set once listening for the signal fails, after which no more notifications are received.
+    closed: bool,
+    /// The wakers of pending streams, by id.
+    wakers: BTreeMap<u64, Waker>,
+    next_id: u64,
+}
+
+impl Registration {
+    /// Returns the registration for `kind`, installing its handler if this is the first use.
+    fn get(kind: SignalKind) -> Result<Arc<Self>> {
+        let mut registry = REGISTRY.lock().unwrap();
+        if let Some((_, registration)) = registry.iter().find(|(k, _)| *k == kind) {
+            return Ok(registration.clone());
+        }
+        let signals = async_signal::Signals::new([async_signal(kind)])?;
+        let registration = Arc::new(Self::default());
+        let driver = registration.clone();
+        std::thread::Builder::new()
+            .name("byor-signal".into())
+            .spawn(move || driver.run(signals))?;
+        registry.push((kind, registration.clone()));
+        Ok(registration)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+
+    fn run(&self, mut signals: async_signal::Signals) {
+        loop {
+            let signal = futures_lite::future::block_on(signals.next());
+            let mut state = self.lock();
+            match signal {
+                Some(Ok(_)) => state.received += 1,
+                _ => state.closed = true,
+            }
+            let closed = state.closed;
+            let wakers = std::mem::take(&mut state.wakers);
+            drop(state);
+            wakers.into_values().for_each(Waker::wake);
+            if closed {
+                return;
+            }
+        }
+    }
+}
+
+fn async_signal(kind: SignalKind) -> AsyncSignal {
+    match kind {
+        SignalKind::Alarm => AsyncSignal::Alarm,
+        SignalKind::Child => AsyncSignal::Child,
+        SignalKind::Hangup => AsyncSignal::Hup,
+        SignalKind::Interrupt => AsyncSignal::Int,
+        SignalKind::Io => AsyncSignal::Io,
+        SignalKind::Pipe => AsyncSignal::Pipe,
+        SignalKind::Quit => AsyncSignal::Quit,
+        SignalKind::Terminate => AsyncSignal::Term,
+        SignalKind::UserDefined1 => AsyncSignal::Usr1,
+        SignalKind::UserDefined2 => AsyncSignal::Usr2,
+        SignalKind::WindowChange => AsyncSignal::Winch,
+    }
+}
+
+/// A stream of notifications of a Unix signal, backed by [`async_signal::Signals`].
+///
+/// The stream ends for good if an error occurs while listening for the signal.
+pub struct Signals {
+    registration: Arc<Registration>,
+    id: u64,
+    /// The number of notifications the registration had received when this last yielded.
+    received: u64,
+}
+
+impl Stream for Signals {
+    type Item = ();
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let mut state = this.registration.lock();
+        if state.received != this.received {
+            this.received = state.received;
+            state.wakers.remove(&this.id);
+            Poll::Ready(Some(()))
+        } else if state.closed {
+            Poll::Ready(None)
+        } else {
+            state.wakers.insert(this.id, cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Signals {
+    fn drop(&mut self) {
+        self.registration.lock().wakers.remove(&self.id);
+    }
+}
+
+impl Signal for Signals {}
+
+impl SendSignal for Signals {}
+
+pub(crate) async fn ctrl_c() -> Result<()> {
+    match signal(SignalKind::Interrupt)?.next().await {
+        Some(()) => Ok(()),
+        None => Err(Error::other("signal stream ended")),
+    }
+}
+
+pub(crate) fn signal(kind: SignalKind) -> Result<Signals> {
+    let registration = Registration::get(kind)?;
+    let mut state = registration.lock();
+    let id = state.next_id;
+    state.next_id += 1;
+    let received = state.received;
+    drop(state);
+    Ok(Signals {
+        registration,
+        id,
+        received,
+    })
+}
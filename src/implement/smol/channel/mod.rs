@@ -1 +1,2 @@
 mod mpsc;
+mod oneshot;
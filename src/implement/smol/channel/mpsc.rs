@@ -9,6 +9,16 @@ impl<T: 'static> Sender<T> for smol::channel::Sender<T> {
     }
 }
 
+impl<T: 'static> SenderExt<T> for SmolSender<T> {
+    fn closed(&mut self) -> impl Future<Output = ()> {
+        async_channel::Sender::closed(self)
+    }
+
+    fn same_channel(&self, other: &Self) -> bool {
+        async_channel::Sender::same_channel(self, other)
+    }
+}
+
 impl<T: 'static> BoundedSender<T> for SmolSender<T> {
     type TrySendError = smol::channel::TrySendError<T>;
 
@@ -43,6 +53,28 @@ impl<T: 'static> UnboundedSender<T> for SmolSender<T> {
     }
 }
 
+impl<T: Send + 'static> SendSenderExt<T> for SmolSender<T> {
+    fn closed(&mut self) -> impl Future<Output = ()> + Send {
+        <Self as SenderExt<T>>::closed(self)
+    }
+
+    fn same_channel(&self, other: &Self) -> bool {
+        <Self as SenderExt<T>>::same_channel(self, other)
+    }
+}
+
+impl<T: Send + 'static> SendBoundedSender<T> for SmolSender<T> {
+    type TrySendError = <Self as BoundedSender<T>>::TrySendError;
+
+    fn send(&mut self, message: T) -> impl Future<Output = Result<(), Self::SendError>> + Send {
+        <Self as BoundedSender<T>>::send(self, message)
+    }
+
+    fn try_send(&mut self, message: T) -> Result<(), Self::TrySendError> {
+        <Self as BoundedSender<T>>::try_send(self, message)
+    }
+}
+
 impl RuntimeMpsc for Smol {
     type BoundedSender<T: 'static> = smol::channel::Sender<T>;
     type BoundedReceiver<T: 'static> = smol::channel::Receiver<T>;
@@ -60,3 +92,54 @@ impl RuntimeMpsc for Smol {
         smol::channel::unbounded()
     }
 }
+
+impl SendRuntimeMpsc for Smol {
+    type BoundedSender<T: Send + 'static> = <Self as RuntimeMpsc>::BoundedSender<T>;
+    type BoundedReceiver<T: Send + 'static> = <Self as RuntimeMpsc>::BoundedReceiver<T>;
+
+    fn bounded_channel<T: Send + 'static>(
+        buffer: usize,
+    ) -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>) {
+        <Self as RuntimeMpsc>::bounded_channel(buffer)
+    }
+
+    type UnboundedSender<T: Send + 'static> = <Self as RuntimeMpsc>::UnboundedSender<T>;
+    type UnboundedReceiver<T: Send + 'static> = <Self as RuntimeMpsc>::UnboundedReceiver<T>;
+
+    fn unbounded_channel<T: Send + 'static>()
+    -> (Self::UnboundedSender<T>, Self::UnboundedReceiver<T>) {
+        <Self as RuntimeMpsc>::unbounded_channel()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        channel::mpsc::{Receiver, RuntimeMpsc, SenderExt},
+        runtime::Smol,
+    };
+    use futures_lite::future::{block_on, poll_once};
+
+    // The senders have inherent methods of the same names, so the trait is called explicitly.
+
+    #[test]
+    fn closed_resolves_once_the_receiver_is_closed() {
+        let (mut sender, mut receiver) = <Smol as RuntimeMpsc>::bounded_channel::<i32>(1);
+        assert_eq!(block_on(poll_once(SenderExt::closed(&mut sender))), None);
+        Receiver::close(&mut receiver);
+        block_on(SenderExt::closed(&mut sender));
+
+        let (mut sender, receiver) = <Smol as RuntimeMpsc>::unbounded_channel::<i32>();
+        let closed = std::thread::spawn(move || block_on(SenderExt::closed(&mut sender)));
+        drop(receiver);
+        closed.join().unwrap();
+    }
+
+    #[test]
+    fn same_channel() {
+        let (sender, _receiver) = <Smol as RuntimeMpsc>::bounded_channel::<i32>(1);
+        let (other, _other_receiver) = <Smol as RuntimeMpsc>::bounded_channel::<i32>(1);
+        assert!(SenderExt::same_channel(&sender, &sender.clone()));
+        assert!(!SenderExt::same_channel(&sender, &other));
+    }
+}
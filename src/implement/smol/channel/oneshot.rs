@@ -0,0 +1,166 @@
+//! A oneshot channel made of two [`smol::channel`]s, since smol doesn't provide one.
+//!
+//! The value is sent on a channel with a capacity of one. Nothing is ever sent on the second
+//! channel, which goes the other way: it's only closed along with the first one by the receiver,
+//! so that the sender can wait for that as a stream.
+//!
+//! The receiving ends are boxed, since they can only be polled as streams while pinned.
+
+use crate::{channel::oneshot, runtime::Smol};
+use futures_lite::Stream;
+use smol::channel::{RecvError, TryRecvError};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pub struct Sender<T> {
+    value: smol::channel::Sender<T>,
+    closed: Pin<Box<smol::channel::Receiver<()>>>,
+}
+
+pub struct Receiver<T> {
+    value: Pin<Box<smol::channel::Receiver<T>>>,
+    closed: smol::channel::Sender<()>,
+}
+
+impl<T> oneshot::Sender<T> for Sender<T> {
+    fn send(self, t: T) -> Result<(), T> {
+        self.value.try_send(t).map_err(|error| error.into_inner())
+    }
+
+    fn is_closed(&self) -> bool {
+        self.value.is_closed()
+    }
+
+    fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if self.value.is_closed() {
+            return Poll::Ready(());
+        }
+        self.closed.as_mut().poll_next(cx).map(|_| ())
+    }
+}
+
+impl<T> Future for Receiver<T> {
+    type Output = Result<T, RecvError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut()
+            .value
+            .as_mut()
+            .poll_next(cx)
+            .map(|value| value.ok_or(RecvError))
+    }
+}
+
+impl<T> oneshot::Receiver<T> for Receiver<T> {
+    type TryRecvError = TryRecvError;
+    type RecvError = RecvError;
+
+    fn close(&mut self) {
+        self.value.close();
+        self.closed.close();
+    }
+
+    fn try_recv(&mut self) -> Result<Option<T>, Self::TryRecvError> {
+        match self.value.try_recv() {
+            Ok(t) => Ok(Some(t)),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Closed) => Err(TryRecvError::Closed),
+        }
+    }
+}
+
+impl<T: Send> oneshot::SendSender<T> for Sender<T> {
+    fn send(self, t: T) -> Result<(), T> {
+        <Self as oneshot::Sender<T>>::send(self, t)
+    }
+
+    fn is_closed(&self) -> bool {
+        <Self as oneshot::Sender<T>>::is_closed(self)
+    }
+
+    fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        <Self as oneshot::Sender<T>>::poll_closed(self, cx)
+    }
+}
+
+impl oneshot::RuntimeOneshot for Smol {
+    type OneshotSender<T> = Sender<T>;
+    type OneshotReceiver<T> = Receiver<T>;
+
+    fn channel<T>() -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>) {
+        let (value_sender, value_receiver) = smol::channel::bounded(1);
+        let (closed_sender, closed_receiver) = smol::channel::bounded(1);
+        (
+            Sender {
+                value: value_sender,
+                closed: Box::pin(closed_receiver),
+            },
+            Receiver {
+                value: Box::pin(value_receiver),
+                closed: closed_sender,
+            },
+        )
+    }
+}
+
+impl oneshot::SendRuntimeOneshot for Smol {
+    type OneshotSender<T: Send> = <Self as oneshot::RuntimeOneshot>::OneshotSender<T>;
+    type OneshotReceiver<T: Send> = <Self as oneshot::RuntimeOneshot>::OneshotReceiver<T>;
+
+    fn channel<T: Send>() -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>) {
+        <Self as oneshot::RuntimeOneshot>::channel()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        channel::oneshot::{Receiver as _, RuntimeOneshot, Sender as _},
+        runtime::Smol,
+    };
+    use futures_lite::future::{block_on, poll_once};
+    use smol::channel::{RecvError, TryRecvError};
+
+    #[test]
+    fn send_and_receive() {
+        let (sender, receiver) = <Smol as RuntimeOneshot>::channel();
+        assert_eq!(sender.send(1), Ok(()));
+        assert_eq!(block_on(receiver), Ok(1));
+    }
+
+    #[test]
+    fn sender_dropped_before_send() {
+        let (sender, mut receiver) = <Smol as RuntimeOneshot>::channel::<i32>();
+        assert_eq!(receiver.try_recv(), Ok(None));
+        drop(sender);
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+        assert_eq!(block_on(receiver), Err(RecvError));
+    }
+
+    #[test]
+    fn receiver_dropped_returns_value() {
+        let (sender, receiver) = <Smol as RuntimeOneshot>::channel();
+        assert!(!sender.is_closed());
+        drop(receiver);
+        assert!(sender.is_closed());
+        assert_eq!(sender.send(1), Err(1));
+    }
+
+    #[test]
+    fn closed_resolves() {
+        let (mut sender, receiver) = <Smol as RuntimeOneshot>::channel::<i32>();
+        assert_eq!(block_on(poll_once(sender.closed())), None);
+        drop(receiver);
+        block_on(sender.closed());
+
+        let (mut sender, mut receiver) = <Smol as RuntimeOneshot>::channel();
+        let closed = std::thread::spawn(move || {
+            block_on(sender.closed());
+            sender.send(1)
+        });
+        receiver.close();
+        assert_eq!(closed.join().unwrap(), Err(1));
+    }
+}
@@ -1,14 +1,23 @@
-use crate::{executor::local::*, runtime::Smol};
+use crate::{executor::local::*, implement::task_set::HandleSet, runtime::Smol};
 
 impl Executor for smol::LocalExecutor<'_> {
-    type Handle<T: 'static> = smol::Task<T>;
+    type Handle<T: 'static> = super::JoinHandle<T>;
+    type TaskSet<T: 'static> = HandleSet<Self, Self::Handle<T>>;
 
     fn spawn<T: 'static>(&self, future: impl Future<Output = T> + 'static) -> Self::Handle<T> {
-        self.spawn(future)
+        let (task, abort) = super::task(future);
+        super::JoinHandle::new(self.spawn(task), abort)
+    }
+
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T> {
+        super::JoinHandle::unblock(f)
     }
 
     fn block_on<T>(&self, future: impl Future<Output = T>) -> T {
-        smol::block_on(future)
+        smol::block_on(self.run(future))
     }
 
     fn new() -> std::io::Result<Self> {
@@ -4,10 +4,101 @@ mod multi_thread;
 #[cfg(feature = "local-exec")]
 mod local;
 
-impl<T: 'static> crate::executor::Handle<T> for smol::Task<T> {
-    type Wrap<U> = U;
+use crate::{
+    executor::{AbortHandle as _, JoinError},
+    implement::abort::{AbortHandle, abortable},
+};
+use smol::future::FutureExt;
+use std::{
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    task::{Context, Poll},
+    thread,
+};
+
+/// A [`Handle`](crate::executor::Handle) to a task spawned on smol.
+pub struct JoinHandle<T> {
+    task: async_task::FallibleTask<Option<thread::Result<T>>>,
+    abort: AbortHandle,
+}
+
+impl<T> JoinHandle<T> {
+    fn new(task: smol::Task<Option<thread::Result<T>>>, abort: AbortHandle) -> Self {
+        Self {
+            task: task.fallible(),
+            abort,
+        }
+    }
+
+    /// Returns a handle to a task that has already been cancelled.
+    #[cfg(feature = "exec")]
+    fn cancelled() -> Self
+    where
+        T: Send + 'static,
+    {
+        let (runnable, task) = async_task::spawn(std::future::pending(), |_| {});
+        drop(runnable);
+        Self::new(task, AbortHandle::finished())
+    }
+
+    /// Runs `f` on smol's blocking thread pool, catching any panic it produces.
+    fn unblock(f: impl FnOnce() -> T + Send + 'static) -> Self
+    where
+        T: Send + 'static,
+    {
+        let abort = AbortHandle::default();
+        let task = smol::unblock({
+            let guard = abort.finished_guard();
+            let abort = abort.clone();
+            move || {
+                let _guard = guard;
+                (!abort.is_aborted()).then(|| std::panic::catch_unwind(AssertUnwindSafe(f)))
+            }
+        });
+        Self::new(task, abort)
+    }
+}
+
+/// Wraps `future` into a task ready to be spawned, returning it along with a handle to abort it.
+///
+/// The task catches any panic produced by `future`, and completes with `None` if aborted.
+fn task<F: Future>(
+    future: F,
+) -> (
+    impl Future<Output = Option<thread::Result<F::Output>>>,
+    AbortHandle,
+) {
+    abortable(AssertUnwindSafe(future).catch_unwind())
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.task.poll(cx).map(|output| match output {
+            Some(Some(Ok(output))) => Ok(output),
+            Some(Some(Err(payload))) => Err(JoinError::panic(payload)),
+            Some(None) | None => Err(JoinError::cancelled()),
+        })
+    }
+}
+
+impl<T: 'static> crate::executor::Handle<T> for JoinHandle<T> {
+    type AbortHandle = AbortHandle;
 
     fn detach(self) {
-        self.detach();
+        self.task.detach();
+    }
+
+    fn abort(&self) {
+        self.abort.abort();
+    }
+
+    fn is_finished(&self) -> bool {
+        self.task.is_finished() || self.abort.is_finished()
+    }
+
+    fn abort_handle(&self) -> Self::AbortHandle {
+        self.abort.clone()
     }
 }
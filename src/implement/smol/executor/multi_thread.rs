@@ -1,24 +1,161 @@
-use crate::{executor::*, runtime::Smol};
+use super::JoinHandle;
+use crate::{
+    executor::*,
+    implement::{
+        builder::{Build, Builder, Threads},
+        task_set::HandleSet,
+    },
+    runtime::Smol,
+};
+use smol::{channel, lock::Semaphore};
+use std::{
+    cell::RefCell,
+    sync::{Arc, Weak},
+    time::Duration,
+};
 
-impl Executor for smol::Executor<'_> {
-    type Handle<T: 'static> = smol::Task<T>;
+thread_local! {
+    static CURRENT: RefCell<Option<SmolSpawner>> = const { RefCell::new(None) };
+}
+
+/// A multi-threaded [`smol::Executor`].
+///
+/// The executor is run by a set of worker threads, which stop once it is dropped. The inner
+/// executor, and with it every task that hasn't completed, is dropped as soon as all of them have
+/// stopped.
+pub struct SmolExecutor {
+    executor: Arc<smol::Executor<'static>>,
+    spawner: SmolSpawner,
+    /// Closed when the executor is dropped, signaling the worker threads to stop.
+    stop: channel::Sender<()>,
+    threads: Arc<Threads>,
+}
+
+/// A [`Spawner`] for a [`SmolExecutor`].
+///
+/// Spawners only hold a weak reference to the executor, so that they don't keep its tasks alive
+/// after it has been shut down.
+#[derive(Clone)]
+pub struct SmolSpawner {
+    executor: Weak<smol::Executor<'static>>,
+    /// Limits how many blocking functions spawned on the executor can run at once.
+    blocking: Option<Arc<Semaphore>>,
+}
+
+impl Executor for SmolExecutor {
+    type Handle<T: 'static> = JoinHandle<T>;
+    type TaskSet<T: Send + 'static> = HandleSet<Self, Self::Handle<T>>;
+    type Spawner = SmolSpawner;
+    type Builder = Builder<Self>;
 
     fn spawn<T: Send + 'static>(
         &self,
         future: impl Future<Output = T> + Send + 'static,
     ) -> Self::Handle<T> {
-        self.spawn(future)
+        self.spawner.spawn(future)
+    }
+
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T> {
+        self.spawner.spawn_blocking(f)
     }
 
     fn block_on<T>(&self, future: impl Future<Output = T>) -> T {
-        smol::block_on(future)
+        let _guard = CurrentGuard(CURRENT.replace(Some(self.spawner.clone())));
+        smol::block_on(self.executor.run(future))
+    }
+
+    fn shutdown_timeout(self, timeout: Duration) {
+        self.stop.close();
+        self.threads.wait(timeout);
+    }
+
+    fn builder() -> Self::Builder {
+        Builder::default()
+    }
+
+    fn spawner(&self) -> Self::Spawner {
+        self.spawner.clone()
+    }
+
+    fn try_current() -> Option<Self::Spawner> {
+        CURRENT.with_borrow(Clone::clone)
     }
+}
+
+impl Build for SmolExecutor {
+    fn build(builder: &Builder<Self>) -> std::io::Result<Self> {
+        let executor = Arc::new(smol::Executor::new());
+        let spawner = SmolSpawner {
+            executor: Arc::downgrade(&executor),
+            blocking: builder
+                .blocking_limit()
+                .map(|limit| Arc::new(Semaphore::new(limit))),
+        };
+        let (stop, stopped) = channel::bounded(1);
+        let threads = Arc::new(Threads::default());
+        for index in 0..builder.worker_thread_count() {
+            let executor = executor.clone();
+            let spawner = spawner.clone();
+            let stopped = stopped.clone();
+            builder.thread_config().spawn(index, &threads, move || {
+                CURRENT.set(Some(spawner));
+                let _ = smol::block_on(executor.run(stopped.recv()));
+            })?;
+        }
+        Ok(Self {
+            executor,
+            spawner,
+            stop,
+            threads,
+        })
+    }
+}
+
+impl Spawner for SmolSpawner {
+    type Handle<T: Send + 'static> = JoinHandle<T>;
+
+    fn spawn<T: Send + 'static>(
+        &self,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Self::Handle<T> {
+        match self.executor.upgrade() {
+            Some(executor) => {
+                let (task, abort) = super::task(future);
+                JoinHandle::new(executor.spawn(task), abort)
+            }
+            None => JoinHandle::cancelled(),
+        }
+    }
+
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T> {
+        let Some(semaphore) = self.blocking.clone() else {
+            return JoinHandle::unblock(f);
+        };
+        self.spawn(async move {
+            let _permit = semaphore.acquire_arc().await;
+            match JoinHandle::unblock(f).await {
+                Ok(output) => output,
+                Err(error) => std::panic::resume_unwind(error.into_panic()),
+            }
+        })
+    }
+}
+
+/// Restores the previous current executor when dropped.
+struct CurrentGuard(Option<SmolSpawner>);
 
-    fn new() -> std::io::Result<Self> {
-        Ok(smol::Executor::new())
+impl Drop for CurrentGuard {
+    fn drop(&mut self) {
+        CURRENT.set(self.0.take());
     }
 }
 
 impl RuntimeExecutor for Smol {
-    type Executor = smol::Executor<'static>;
+    type Executor = SmolExecutor;
 }
@@ -154,6 +154,127 @@ impl SemaphorePermit for smol::lock::SemaphoreGuard<'_> {
     }
 }
 
+impl<T: ?Sized + Send> SendMutex<T> for smol::lock::Mutex<T> {
+    type Guard<'a>
+        = <Self as Mutex<T>>::Guard<'a>
+    where
+        Self: 'a;
+
+    fn new(t: T) -> Self
+    where
+        T: Sized,
+    {
+        <Self as Mutex<T>>::new(t)
+    }
+
+    fn lock(&self) -> impl Future<Output = Self::Guard<'_>> + Send {
+        <Self as Mutex<T>>::lock(self)
+    }
+
+    fn try_lock(&self) -> Option<Self::Guard<'_>> {
+        <Self as Mutex<T>>::try_lock(self)
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        <Self as Mutex<T>>::get_mut(self)
+    }
+
+    fn into_inner(self) -> T
+    where
+        T: Sized,
+    {
+        <Self as Mutex<T>>::into_inner(self)
+    }
+}
+
+impl<T: ?Sized + Send + Sync> SendRwLock<T> for smol::lock::RwLock<T> {
+    type ReadGuard<'a>
+        = <Self as RwLock<T>>::ReadGuard<'a>
+    where
+        Self: 'a;
+    type WriteGuard<'a>
+        = <Self as RwLock<T>>::WriteGuard<'a>
+    where
+        Self: 'a;
+
+    fn new(t: T) -> Self
+    where
+        T: Sized,
+    {
+        <Self as RwLock<T>>::new(t)
+    }
+
+    fn read(&self) -> impl Future<Output = Self::ReadGuard<'_>> + Send {
+        <Self as RwLock<T>>::read(self)
+    }
+
+    fn blocking_read(&self) -> Self::ReadGuard<'_> {
+        <Self as RwLock<T>>::blocking_read(self)
+    }
+
+    fn try_read(&self) -> Option<Self::ReadGuard<'_>> {
+        <Self as RwLock<T>>::try_read(self)
+    }
+
+    fn write(&self) -> impl Future<Output = Self::WriteGuard<'_>> + Send {
+        <Self as RwLock<T>>::write(self)
+    }
+
+    fn blocking_write(&self) -> Self::WriteGuard<'_> {
+        <Self as RwLock<T>>::blocking_write(self)
+    }
+
+    fn try_write(&self) -> Option<Self::WriteGuard<'_>> {
+        <Self as RwLock<T>>::try_write(self)
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        <Self as RwLock<T>>::get_mut(self)
+    }
+
+    fn into_inner(self) -> T
+    where
+        T: Sized,
+    {
+        <Self as RwLock<T>>::into_inner(self)
+    }
+}
+
+impl SendBarrier for smol::lock::Barrier {
+    type WaitResult = <Self as Barrier>::WaitResult;
+
+    fn new(n: usize) -> Self {
+        <Self as Barrier>::new(n)
+    }
+
+    fn wait(&self) -> impl Future<Output = Self::WaitResult> + Send {
+        <Self as Barrier>::wait(self)
+    }
+}
+
+impl SendSemaphore for smol::lock::Semaphore {
+    type Permit<'a>
+        = <Self as Semaphore>::Permit<'a>
+    where
+        Self: 'a;
+
+    fn new(permits: usize) -> Self {
+        <Self as Semaphore>::new(permits)
+    }
+
+    fn add_permits(&self, n: usize) {
+        <Self as Semaphore>::add_permits(self, n)
+    }
+
+    fn acquire(&self) -> impl Future<Output = Option<Self::Permit<'_>>> + Send {
+        <Self as Semaphore>::acquire(self)
+    }
+
+    fn try_acquire(&self) -> Option<Self::Permit<'_>> {
+        <Self as Semaphore>::try_acquire(self)
+    }
+}
+
 impl RuntimeLock for Smol {
     type Mutex<T: ?Sized> = smol::lock::Mutex<T>;
 }
@@ -163,3 +284,13 @@ impl RuntimeLockExt for Smol {
     type Barrier = smol::lock::Barrier;
     type Semaphore = smol::lock::Semaphore;
 }
+
+impl SendRuntimeLock for Smol {
+    type Mutex<T: ?Sized + Send> = smol::lock::Mutex<T>;
+}
+
+impl SendRuntimeLockExt for Smol {
+    type RwLock<T: ?Sized + Send + Sync> = smol::lock::RwLock<T>;
+    type Barrier = smol::lock::Barrier;
+    type Semaphore = smol::lock::Semaphore;
+}
@@ -1,12 +1,21 @@
 use crate::{net::*, runtime::Smol};
+use smol::{Async, net::unix};
 use std::{
     io::Result,
     net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+    os::unix::net::SocketAddr as UnixSocketAddr,
+    path::Path,
+    sync::Arc,
 };
 
 impl TcpStream for smol::net::TcpStream {
     async fn connect(addr: impl ToSocketAddrs) -> Result<Self> {
-        smol::net::TcpStream::connect(Smol::get_socket_addrs(addr.kind()).await?.as_slice()).await
+        smol::net::TcpStream::connect(
+            <Smol as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+        .await
     }
     fn local_addr(&self) -> Result<SocketAddr> {
         self.local_addr()
@@ -32,11 +41,18 @@ impl TcpStream for smol::net::TcpStream {
 }
 
 impl TcpListener for smol::net::TcpListener {
-    async fn accept(&self) -> Result<(impl TcpStream, SocketAddr)> {
+    type Stream = smol::net::TcpStream;
+
+    async fn accept(&self) -> Result<(Self::Stream, SocketAddr)> {
         self.accept().await
     }
     async fn bind(addr: impl ToSocketAddrs) -> Result<Self> {
-        smol::net::TcpListener::bind(Smol::get_socket_addrs(addr.kind()).await?.as_slice()).await
+        smol::net::TcpListener::bind(
+            <Smol as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+        .await
     }
     fn local_addr(&self) -> Result<SocketAddr> {
         self.local_addr()
@@ -51,14 +67,23 @@ impl TcpListener for smol::net::TcpListener {
 
 impl UdpSocket for smol::net::UdpSocket {
     async fn bind(addr: impl ToSocketAddrs) -> Result<Self> {
-        smol::net::UdpSocket::bind(Smol::get_socket_addrs(addr.kind()).await?.as_slice()).await
+        smol::net::UdpSocket::bind(
+            <Smol as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+        .await
     }
     fn broadcast(&self) -> Result<bool> {
         self.broadcast()
     }
     async fn connect(&self, addr: impl ToSocketAddrs) -> Result<()> {
-        self.connect(Smol::get_socket_addrs(addr.kind()).await?.as_slice())
-            .await
+        self.connect(
+            <Smol as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+        .await
     }
     fn join_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
         self.join_multicast_v4(multiaddr, interface)
@@ -103,8 +128,13 @@ impl UdpSocket for smol::net::UdpSocket {
         self.send(buf)
     }
     async fn send_to(&self, buf: &[u8], addr: impl ToSocketAddrs) -> Result<usize> {
-        self.send_to(buf, Smol::get_socket_addrs(addr.kind()).await?.as_slice())
-            .await
+        self.send_to(
+            buf,
+            <Smol as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+        .await
     }
     fn set_broadcast(&self, on: bool) -> Result<()> {
         self.set_broadcast(on)
@@ -126,10 +156,105 @@ impl UdpSocket for smol::net::UdpSocket {
     }
 }
 
+impl UnixStream for unix::UnixStream {
+    fn connect(path: impl AsRef<Path>) -> impl Future<Output = Result<Self>> {
+        unix::UnixStream::connect(path)
+    }
+    async fn connect_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        let addr = addr.clone();
+        let stream =
+            smol::unblock(move || std::os::unix::net::UnixStream::connect_addr(&addr)).await?;
+        unix::UnixStream::try_from(stream)
+    }
+    fn pair() -> Result<(Self, Self)> {
+        unix::UnixStream::pair()
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        self.local_addr()
+    }
+    fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        self.peer_addr()
+    }
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn peer_cred(&self) -> Result<UCred> {
+        let cred = rustix::net::sockopt::socket_peercred(self)?;
+        Ok(UCred {
+            uid: cred.uid.as_raw(),
+            gid: cred.gid.as_raw(),
+            pid: Some(cred.pid.as_raw_nonzero().get()),
+        })
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn peer_cred(&self) -> Result<UCred> {
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+}
+
+impl UnixListener for unix::UnixListener {
+    type Stream = unix::UnixStream;
+
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, UnixSocketAddr)>> {
+        self.accept()
+    }
+    fn bind(path: impl AsRef<Path>) -> Result<Self> {
+        unix::UnixListener::bind(path)
+    }
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        unix::UnixListener::try_from(std::os::unix::net::UnixListener::bind_addr(addr)?)
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        self.local_addr()
+    }
+}
+
+impl UnixDatagram for unix::UnixDatagram {
+    fn bind(path: impl AsRef<Path>) -> Result<Self> {
+        unix::UnixDatagram::bind(path)
+    }
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        unix::UnixDatagram::try_from(std::os::unix::net::UnixDatagram::bind_addr(addr)?)
+    }
+    fn unbound() -> Result<Self> {
+        unix::UnixDatagram::unbound()
+    }
+    fn pair() -> Result<(Self, Self)> {
+        unix::UnixDatagram::pair()
+    }
+    fn connect(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.connect(path)
+    }
+    fn connect_addr(&self, addr: &UnixSocketAddr) -> Result<()> {
+        Arc::<Async<std::os::unix::net::UnixDatagram>>::from(self.clone())
+            .get_ref()
+            .connect_addr(addr)
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        self.local_addr()
+    }
+    fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        self.peer_addr()
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> {
+        self.recv(buf)
+    }
+    fn recv_from(&self, buf: &mut [u8]) -> impl Future<Output = Result<(usize, UnixSocketAddr)>> {
+        self.recv_from(buf)
+    }
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>> {
+        self.send(buf)
+    }
+    fn send_to(&self, buf: &[u8], path: impl AsRef<Path>) -> impl Future<Output = Result<usize>> {
+        self.send_to(buf, path)
+    }
+}
+
 impl RuntimeNet for Smol {
     type TcpStream = smol::net::TcpStream;
     type TcpListener = smol::net::TcpListener;
     type UdpSocket = smol::net::UdpSocket;
+    type UnixStream = unix::UnixStream;
+    type UnixListener = unix::UnixListener;
+    type UnixDatagram = unix::UnixDatagram;
 
     async fn get_socket_addrs(kind: SocketAddrsKind<'_>) -> Result<Vec<std::net::SocketAddr>> {
         use smol::net::resolve;
@@ -146,3 +271,234 @@ impl RuntimeNet for Smol {
         }
     }
 }
+
+impl SendTcpStream for smol::net::TcpStream {
+    fn connect(addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as TcpStream>::connect(addr)
+    }
+    fn local_addr(&self) -> Result<SocketAddr> {
+        <Self as TcpStream>::local_addr(self)
+    }
+    fn nodelay(&self) -> Result<bool> {
+        <Self as TcpStream>::nodelay(self)
+    }
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as TcpStream>::peek(self, buf)
+    }
+    fn peer_addr(&self) -> Result<SocketAddr> {
+        <Self as TcpStream>::peer_addr(self)
+    }
+    fn set_nodelay(&self, nodelay: bool) -> Result<()> {
+        <Self as TcpStream>::set_nodelay(self, nodelay)
+    }
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        <Self as TcpStream>::set_ttl(self, ttl)
+    }
+    fn ttl(&self) -> Result<u32> {
+        <Self as TcpStream>::ttl(self)
+    }
+}
+
+impl SendTcpListener for smol::net::TcpListener {
+    type Stream = smol::net::TcpStream;
+
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, SocketAddr)>> + Send {
+        <Self as TcpListener>::accept(self)
+    }
+    fn bind(addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as TcpListener>::bind(addr)
+    }
+    fn local_addr(&self) -> Result<SocketAddr> {
+        <Self as TcpListener>::local_addr(self)
+    }
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        <Self as TcpListener>::set_ttl(self, ttl)
+    }
+    fn ttl(&self) -> Result<u32> {
+        <Self as TcpListener>::ttl(self)
+    }
+}
+
+impl SendUdpSocket for smol::net::UdpSocket {
+    fn bind(addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as UdpSocket>::bind(addr)
+    }
+    fn broadcast(&self) -> Result<bool> {
+        <Self as UdpSocket>::broadcast(self)
+    }
+    fn connect(&self, addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<()>> + Send {
+        <Self as UdpSocket>::connect(self, addr)
+    }
+    fn join_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
+        <Self as UdpSocket>::join_multicast_v4(self, multiaddr, interface)
+    }
+    fn join_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()> {
+        <Self as UdpSocket>::join_multicast_v6(self, multiaddr, interface)
+    }
+    fn leave_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
+        <Self as UdpSocket>::leave_multicast_v4(self, multiaddr, interface)
+    }
+    fn leave_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()> {
+        <Self as UdpSocket>::leave_multicast_v6(self, multiaddr, interface)
+    }
+    fn local_addr(&self) -> Result<SocketAddr> {
+        <Self as UdpSocket>::local_addr(self)
+    }
+    fn multicast_loop_v4(&self) -> Result<bool> {
+        <Self as UdpSocket>::multicast_loop_v4(self)
+    }
+    fn multicast_loop_v6(&self) -> Result<bool> {
+        <Self as UdpSocket>::multicast_loop_v6(self)
+    }
+    fn multicast_ttl_v4(&self) -> Result<u32> {
+        <Self as UdpSocket>::multicast_ttl_v4(self)
+    }
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UdpSocket>::peek(self, buf)
+    }
+    fn peek_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = Result<(usize, SocketAddr)>> + Send {
+        <Self as UdpSocket>::peek_from(self, buf)
+    }
+    fn peer_addr(&self) -> Result<SocketAddr> {
+        <Self as UdpSocket>::peer_addr(self)
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UdpSocket>::recv(self, buf)
+    }
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = Result<(usize, SocketAddr)>> + Send {
+        <Self as UdpSocket>::recv_from(self, buf)
+    }
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UdpSocket>::send(self, buf)
+    }
+    fn send_to(
+        &self,
+        buf: &[u8],
+        addr: impl ToSocketAddrs + Send,
+    ) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UdpSocket>::send_to(self, buf, addr)
+    }
+    fn set_broadcast(&self, on: bool) -> Result<()> {
+        <Self as UdpSocket>::set_broadcast(self, on)
+    }
+    fn set_multicast_loop_v4(&self, on: bool) -> Result<()> {
+        <Self as UdpSocket>::set_multicast_loop_v4(self, on)
+    }
+    fn set_multicast_loop_v6(&self, on: bool) -> Result<()> {
+        <Self as UdpSocket>::set_multicast_loop_v6(self, on)
+    }
+    fn set_multicast_ttl_v4(&self, ttl: u32) -> Result<()> {
+        <Self as UdpSocket>::set_multicast_ttl_v4(self, ttl)
+    }
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        <Self as UdpSocket>::set_ttl(self, ttl)
+    }
+    fn ttl(&self) -> Result<u32> {
+        <Self as UdpSocket>::ttl(self)
+    }
+}
+
+impl SendUnixStream for unix::UnixStream {
+    fn connect(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Self>> + Send {
+        <Self as UnixStream>::connect(path)
+    }
+    fn connect_addr(addr: &UnixSocketAddr) -> impl Future<Output = Result<Self>> + Send {
+        <Self as UnixStream>::connect_addr(addr)
+    }
+    fn pair() -> Result<(Self, Self)> {
+        <Self as UnixStream>::pair()
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixStream>::local_addr(self)
+    }
+    fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixStream>::peer_addr(self)
+    }
+    fn peer_cred(&self) -> Result<UCred> {
+        <Self as UnixStream>::peer_cred(self)
+    }
+}
+
+impl SendUnixListener for unix::UnixListener {
+    type Stream = unix::UnixStream;
+
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, UnixSocketAddr)>> + Send {
+        <Self as UnixListener>::accept(self)
+    }
+    fn bind(path: impl AsRef<Path>) -> Result<Self> {
+        <Self as UnixListener>::bind(path)
+    }
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        <Self as UnixListener>::bind_addr(addr)
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixListener>::local_addr(self)
+    }
+}
+
+impl SendUnixDatagram for unix::UnixDatagram {
+    fn bind(path: impl AsRef<Path>) -> Result<Self> {
+        <Self as UnixDatagram>::bind(path)
+    }
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self> {
+        <Self as UnixDatagram>::bind_addr(addr)
+    }
+    fn unbound() -> Result<Self> {
+        <Self as UnixDatagram>::unbound()
+    }
+    fn pair() -> Result<(Self, Self)> {
+        <Self as UnixDatagram>::pair()
+    }
+    fn connect(&self, path: impl AsRef<Path>) -> Result<()> {
+        <Self as UnixDatagram>::connect(self, path)
+    }
+    fn connect_addr(&self, addr: &UnixSocketAddr) -> Result<()> {
+        <Self as UnixDatagram>::connect_addr(self, addr)
+    }
+    fn local_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixDatagram>::local_addr(self)
+    }
+    fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        <Self as UnixDatagram>::peer_addr(self)
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UnixDatagram>::recv(self, buf)
+    }
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = Result<(usize, UnixSocketAddr)>> + Send {
+        <Self as UnixDatagram>::recv_from(self, buf)
+    }
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UnixDatagram>::send(self, buf)
+    }
+    fn send_to(
+        &self,
+        buf: &[u8],
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<usize>> + Send {
+        <Self as UnixDatagram>::send_to(self, buf, path)
+    }
+}
+
+impl SendRuntimeNet for Smol {
+    type TcpStream = smol::net::TcpStream;
+    type TcpListener = smol::net::TcpListener;
+    type UdpSocket = smol::net::UdpSocket;
+    type UnixStream = unix::UnixStream;
+    type UnixListener = unix::UnixListener;
+    type UnixDatagram = unix::UnixDatagram;
+
+    fn get_socket_addrs(
+        kind: SocketAddrsKind<'_>,
+    ) -> impl Future<Output = Result<Vec<SocketAddr>>> + Send {
+        <Self as RuntimeNet>::get_socket_addrs(kind)
+    }
+}
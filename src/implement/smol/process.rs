@@ -0,0 +1,11 @@
+use crate::{process::*, runtime::Smol};
+
+impl RuntimeProcess for Smol {
+    type Command = smol::process::Command;
+    type Child = smol::process::Child;
+}
+
+impl SendRuntimeProcess for Smol {
+    type Command = smol::process::Command;
+    type Child = smol::process::Child;
+}
@@ -0,0 +1,21 @@
+use crate::{runtime::Smol, task::*};
+
+impl RuntimeTask for Smol {
+    fn yield_now() -> impl Future<Output = ()> {
+        smol::future::yield_now()
+    }
+
+    fn consume_budget() -> impl Future<Output = ()> {
+        crate::task::consume_budget(std::future::ready(()))
+    }
+}
+
+impl SendRuntimeTask for Smol {
+    fn yield_now() -> impl Future<Output = ()> + Send {
+        <Self as RuntimeTask>::yield_now()
+    }
+
+    fn consume_budget() -> impl Future<Output = ()> + Send {
+        <Self as RuntimeTask>::consume_budget()
+    }
+}
@@ -1,19 +1,96 @@
-use crate::{runtime::Smol, time::Time};
+use crate::{
+    implement::interval::{self, Interval, add},
+    runtime::Smol,
+    time::{self, SendTime, Time},
+};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
 
-impl Time for Smol {
-    fn sleep(duration: std::time::Duration) -> impl Future<Output = std::time::Instant> {
-        smol::Timer::after(duration)
+pub struct Sleep {
+    timer: smol::Timer,
+    deadline: Instant,
+}
+
+impl Sleep {
+    fn new(deadline: Instant) -> Self {
+        Self {
+            timer: smol::Timer::at(deadline),
+            deadline,
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = Instant;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // A timer never completes again once it has, unless it is reset.
+        if time::Sleep::is_elapsed(&*self) {
+            return Poll::Ready(self.deadline);
+        }
+        let deadline = self.deadline;
+        Pin::new(&mut self.timer).poll(cx).map(|_| deadline)
+    }
+}
+
+impl time::Sleep for Sleep {
+    fn reset(&mut self, deadline: Instant) {
+        self.timer.set_at(deadline);
+        self.deadline = deadline;
+    }
+
+    fn deadline(&self) -> Instant {
+        self.deadline
     }
-    fn sleep_until(deadline: std::time::Instant) -> impl Future<Output = std::time::Instant> {
+
+    fn is_elapsed(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+}
+
+impl interval::Timer for smol::Timer {
+    fn at(deadline: Instant) -> Self {
         smol::Timer::at(deadline)
     }
-    fn interval(duration: std::time::Duration) -> impl futures::Stream<Item = std::time::Instant> {
-        smol::Timer::interval(duration)
+
+    fn set_at(&mut self, deadline: Instant) {
+        self.set_at(deadline);
+    }
+}
+
+impl Time for Smol {
+    type Sleep = Sleep;
+
+    fn sleep(duration: Duration) -> Self::Sleep {
+        Sleep::new(add(Instant::now(), duration))
+    }
+    fn sleep_until(deadline: Instant) -> Self::Sleep {
+        Sleep::new(deadline)
+    }
+    fn interval(period: Duration) -> impl time::Interval {
+        Interval::<smol::Timer>::new(Instant::now(), period)
+    }
+    fn interval_at(start: Instant, period: Duration) -> impl time::Interval {
+        Interval::<smol::Timer>::new(start, period)
+    }
+}
+
+impl SendTime for Smol {
+    type Sleep = Sleep;
+
+    fn sleep(duration: Duration) -> Self::Sleep {
+        <Self as Time>::sleep(duration)
+    }
+    fn sleep_until(deadline: Instant) -> Self::Sleep {
+        <Self as Time>::sleep_until(deadline)
+    }
+    fn interval(period: Duration) -> impl time::Interval + Send {
+        <Self as Time>::interval(period)
     }
-    fn interval_at(
-        start: std::time::Instant,
-        duration: std::time::Duration,
-    ) -> impl futures::Stream<Item = std::time::Instant> {
-        smol::Timer::interval_at(start, duration)
+    fn interval_at(start: Instant, period: Duration) -> impl time::Interval + Send {
+        <Self as Time>::interval_at(start, period)
     }
 }
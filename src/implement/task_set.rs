@@ -0,0 +1,71 @@
+//! A [`TaskSet`](crate::executor::TaskSet) for executors that don't provide one, built on top of
+//! their handles.
+
+use crate::executor::JoinError;
+use futures::stream::{FuturesUnordered, StreamExt};
+use std::marker::PhantomData;
+
+/// A set of [`Handle`](crate::executor::Handle)s to tasks spawned on an executor of type `E`.
+pub struct HandleSet<E, H> {
+    handles: FuturesUnordered<H>,
+    executor: PhantomData<fn() -> E>,
+}
+
+impl<E, H> Default for HandleSet<E, H> {
+    fn default() -> Self {
+        Self {
+            handles: FuturesUnordered::new(),
+            executor: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "exec")]
+impl<E, T> crate::executor::TaskSet<T> for HandleSet<E, E::Handle<T>>
+where
+    E: crate::executor::Executor,
+    T: Send + 'static,
+{
+    type Executor = E;
+
+    fn spawn(&mut self, executor: &E, future: impl Future<Output = T> + Send + 'static) {
+        self.handles.push(executor.spawn(future));
+    }
+
+    fn join_next(&mut self) -> impl Future<Output = Option<Result<T, JoinError>>> {
+        self.handles.next()
+    }
+
+    fn abort_all(&mut self) {
+        self.handles.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.handles.len()
+    }
+}
+
+#[cfg(feature = "local-exec")]
+impl<E, T> crate::executor::local::TaskSet<T> for HandleSet<E, E::Handle<T>>
+where
+    E: crate::executor::local::Executor,
+    T: 'static,
+{
+    type Executor = E;
+
+    fn spawn(&mut self, executor: &E, future: impl Future<Output = T> + 'static) {
+        self.handles.push(executor.spawn(future));
+    }
+
+    fn join_next(&mut self) -> impl Future<Output = Option<Result<T, JoinError>>> {
+        self.handles.next()
+    }
+
+    fn abort_all(&mut self) {
+        self.handles.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.handles.len()
+    }
+}
@@ -87,6 +87,38 @@ impl<T: 'static> Receiver<T> for tokio_stream::wrappers::UnboundedReceiverStream
     }
 }
 
+impl<T: Send + 'static> SendSenderExt<T> for TokioSender<T> {
+    fn closed(&mut self) -> impl Future<Output = ()> + Send {
+        <Self as SenderExt<T>>::closed(self)
+    }
+
+    fn same_channel(&self, other: &Self) -> bool {
+        <Self as SenderExt<T>>::same_channel(self, other)
+    }
+}
+
+impl<T: Send + 'static> SendBoundedSender<T> for TokioSender<T> {
+    type TrySendError = <Self as BoundedSender<T>>::TrySendError;
+
+    fn send(&mut self, message: T) -> impl Future<Output = Result<(), Self::SendError>> + Send {
+        <Self as BoundedSender<T>>::send(self, message)
+    }
+
+    fn try_send(&mut self, message: T) -> Result<(), Self::TrySendError> {
+        <Self as BoundedSender<T>>::try_send(self, message)
+    }
+}
+
+impl<T: Send + 'static> SendSenderExt<T> for TokioUnboundedSender<T> {
+    fn closed(&mut self) -> impl Future<Output = ()> + Send {
+        <Self as SenderExt<T>>::closed(self)
+    }
+
+    fn same_channel(&self, other: &Self) -> bool {
+        <Self as SenderExt<T>>::same_channel(self, other)
+    }
+}
+
 impl RuntimeMpsc for Tokio {
     type BoundedSender<T: 'static> = tokio::sync::mpsc::Sender<T>;
     type BoundedReceiver<T: 'static> = tokio_stream::wrappers::ReceiverStream<T>;
@@ -108,3 +140,22 @@ impl RuntimeMpsc for Tokio {
         (tx, rx.into())
     }
 }
+
+impl SendRuntimeMpsc for Tokio {
+    type BoundedSender<T: Send + 'static> = <Self as RuntimeMpsc>::BoundedSender<T>;
+    type BoundedReceiver<T: Send + 'static> = <Self as RuntimeMpsc>::BoundedReceiver<T>;
+
+    fn bounded_channel<T: Send + 'static>(
+        buffer: usize,
+    ) -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>) {
+        <Self as RuntimeMpsc>::bounded_channel(buffer)
+    }
+
+    type UnboundedSender<T: Send + 'static> = <Self as RuntimeMpsc>::UnboundedSender<T>;
+    type UnboundedReceiver<T: Send + 'static> = <Self as RuntimeMpsc>::UnboundedReceiver<T>;
+
+    fn unbounded_channel<T: Send + 'static>()
+    -> (Self::UnboundedSender<T>, Self::UnboundedReceiver<T>) {
+        <Self as RuntimeMpsc>::unbounded_channel()
+    }
+}
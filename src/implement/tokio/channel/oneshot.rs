@@ -32,6 +32,20 @@ impl<T> Receiver<T> for tokio::sync::oneshot::Receiver<T> {
     }
 }
 
+impl<T: Send> SendSender<T> for tokio::sync::oneshot::Sender<T> {
+    fn send(self, t: T) -> Result<(), T> {
+        <Self as Sender<T>>::send(self, t)
+    }
+
+    fn is_closed(&self) -> bool {
+        <Self as Sender<T>>::is_closed(self)
+    }
+
+    fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        <Self as Sender<T>>::poll_closed(self, cx)
+    }
+}
+
 impl RuntimeOneshot for Tokio {
     type OneshotSender<T> = tokio::sync::oneshot::Sender<T>;
     type OneshotReceiver<T> = tokio::sync::oneshot::Receiver<T>;
@@ -40,3 +54,12 @@ impl RuntimeOneshot for Tokio {
         tokio::sync::oneshot::channel()
     }
 }
+
+impl SendRuntimeOneshot for Tokio {
+    type OneshotSender<T: Send> = <Self as RuntimeOneshot>::OneshotSender<T>;
+    type OneshotReceiver<T: Send> = <Self as RuntimeOneshot>::OneshotReceiver<T>;
+
+    fn channel<T: Send>() -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>) {
+        <Self as RuntimeOneshot>::channel()
+    }
+}
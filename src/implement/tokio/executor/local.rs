@@ -0,0 +1,98 @@
+use super::{JoinHandle, join_error};
+use crate::{executor::local::*, runtime::Tokio};
+use tokio::task::{JoinSet, LocalSet};
+use tokio_util::task::AbortOnDropHandle;
+
+/// A thread-local executor made of a current-thread tokio runtime and a [`LocalSet`].
+///
+/// Tasks can be spawned at any time, but only make progress while
+/// [`block_on`](Executor::block_on) is running.
+pub struct TokioLocalExecutor {
+    runtime: tokio::runtime::Runtime,
+    local: LocalSet,
+}
+
+impl Executor for TokioLocalExecutor {
+    type Handle<T: 'static> = JoinHandle<T>;
+    type TaskSet<T: 'static> = JoinSet<T>;
+
+    fn spawn<T: 'static>(&self, future: impl Future<Output = T> + 'static) -> Self::Handle<T> {
+        JoinHandle(AbortOnDropHandle::new(self.local.spawn_local(future)))
+    }
+
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T> {
+        JoinHandle(AbortOnDropHandle::new(self.runtime.spawn_blocking(f)))
+    }
+
+    fn block_on<T>(&self, future: impl Future<Output = T>) -> T {
+        self.local.block_on(&self.runtime, future)
+    }
+
+    fn new() -> std::io::Result<Self> {
+        Ok(Self {
+            runtime: tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()?,
+            local: LocalSet::new(),
+        })
+    }
+}
+
+impl<T: 'static> TaskSet<T> for JoinSet<T> {
+    type Executor = TokioLocalExecutor;
+
+    fn spawn(&mut self, executor: &Self::Executor, future: impl Future<Output = T> + 'static) {
+        self.spawn_local_on(future, &executor.local);
+    }
+
+    async fn join_next(&mut self) -> Option<Result<T, crate::executor::JoinError>> {
+        self.join_next()
+            .await
+            .map(|output| output.map_err(join_error))
+    }
+
+    fn abort_all(&mut self) {
+        self.abort_all();
+        self.detach_all();
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl RuntimeExecutor for Tokio {
+    type Executor = TokioLocalExecutor;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        executor::local::{Executor, RuntimeExecutor, TaskSet},
+        runtime::Tokio,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    #[test]
+    fn runs_non_send_tasks() {
+        let executor = <Tokio as RuntimeExecutor>::Executor::new().unwrap();
+        let count = Rc::new(Cell::new(0));
+        // Each task holds an `Rc` across an await, so it could never be spawned on another thread.
+        let increment = |count: Rc<Cell<u32>>| async move {
+            tokio::task::yield_now().await;
+            count.set(count.get() + 1);
+        };
+
+        let handle = executor.spawn(increment(count.clone()));
+        let mut set = <<Tokio as RuntimeExecutor>::Executor as Executor>::TaskSet::default();
+        TaskSet::spawn(&mut set, &executor, increment(count.clone()));
+        executor.block_on(async {
+            handle.await.unwrap();
+            TaskSet::join_next(&mut set).await.unwrap().unwrap();
+        });
+        assert_eq!(count.get(), 2);
+    }
+}
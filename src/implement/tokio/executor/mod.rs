@@ -0,0 +1,61 @@
+#[cfg(feature = "exec")]
+mod multi_thread;
+
+#[cfg(feature = "local-exec")]
+mod local;
+
+use crate::executor::{AbortHandle, Handle, JoinError};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio_util::task::AbortOnDropHandle;
+
+/// A [`Handle`] to a task spawned on tokio.
+pub struct JoinHandle<T>(AbortOnDropHandle<T>);
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map_err(join_error)
+    }
+}
+
+/// Converts a tokio join error into a byor one.
+fn join_error(e: tokio::task::JoinError) -> JoinError {
+    match e.try_into_panic() {
+        Ok(payload) => JoinError::panic(payload),
+        Err(_) => JoinError::cancelled(),
+    }
+}
+
+impl<T: 'static> Handle<T> for JoinHandle<T> {
+    type AbortHandle = tokio::task::AbortHandle;
+
+    fn detach(self) {
+        self.0.detach();
+    }
+
+    fn abort(&self) {
+        self.0.abort();
+    }
+
+    fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+
+    fn abort_handle(&self) -> Self::AbortHandle {
+        self.0.abort_handle()
+    }
+}
+
+impl AbortHandle for tokio::task::AbortHandle {
+    fn abort(&self) {
+        self.abort();
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_finished()
+    }
+}
@@ -0,0 +1,140 @@
+use super::{JoinHandle, join_error};
+use crate::{executor::*, runtime::Tokio};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+use tokio::runtime::RuntimeFlavor;
+use tokio_util::task::AbortOnDropHandle;
+
+impl Executor for tokio::runtime::Runtime {
+    type Handle<T: 'static> = JoinHandle<T>;
+    type TaskSet<T: Send + 'static> = tokio::task::JoinSet<T>;
+    type Spawner = tokio::runtime::Handle;
+    type Builder = tokio::runtime::Builder;
+
+    fn spawn<T: Send + 'static>(
+        &self,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Self::Handle<T> {
+        JoinHandle(AbortOnDropHandle::new(self.spawn(future)))
+    }
+
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T> {
+        JoinHandle(AbortOnDropHandle::new(self.spawn_blocking(f)))
+    }
+
+    fn block_on<T>(&self, future: impl Future<Output = T>) -> T {
+        self.block_on(future)
+    }
+
+    fn shutdown_timeout(self, timeout: Duration) {
+        self.shutdown_timeout(timeout);
+    }
+
+    fn shutdown_background(self) {
+        self.shutdown_background();
+    }
+
+    fn builder() -> Self::Builder {
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+        builder.enable_all();
+        builder
+    }
+
+    fn spawner(&self) -> Self::Spawner {
+        self.handle().clone()
+    }
+
+    fn try_current() -> Option<Self::Spawner> {
+        tokio::runtime::Handle::try_current()
+            .ok()
+            .filter(|handle| handle.runtime_flavor() != RuntimeFlavor::CurrentThread)
+    }
+}
+
+impl ExecutorBuilder for tokio::runtime::Builder {
+    type Executor = tokio::runtime::Runtime;
+
+    fn worker_threads(&mut self, count: usize) -> &mut Self {
+        self.worker_threads(count)
+    }
+
+    fn thread_name_prefix(&mut self, prefix: impl Into<String>) -> &mut Self {
+        let prefix = prefix.into();
+        let next = AtomicUsize::new(0);
+        self.thread_name_fn(move || format!("{prefix}-{}", next.fetch_add(1, Ordering::Relaxed)))
+    }
+
+    fn thread_stack_size(&mut self, size: usize) -> &mut Self {
+        self.thread_stack_size(size)
+    }
+
+    fn on_thread_start(&mut self, f: impl Fn() + Send + Sync + 'static) -> &mut Self {
+        self.on_thread_start(f)
+    }
+
+    fn on_thread_stop(&mut self, f: impl Fn() + Send + Sync + 'static) -> &mut Self {
+        self.on_thread_stop(f)
+    }
+
+    fn max_blocking_threads(&mut self, count: usize) -> &mut Self {
+        self.max_blocking_threads(count)
+    }
+
+    fn build(&mut self) -> std::io::Result<Self::Executor> {
+        self.build()
+    }
+}
+
+impl Spawner for tokio::runtime::Handle {
+    type Handle<T: Send + 'static> = JoinHandle<T>;
+
+    fn spawn<T: Send + 'static>(
+        &self,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Self::Handle<T> {
+        JoinHandle(AbortOnDropHandle::new(self.spawn(future)))
+    }
+
+    fn spawn_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Self::Handle<T> {
+        JoinHandle(AbortOnDropHandle::new(self.spawn_blocking(f)))
+    }
+}
+
+impl<T: Send + 'static> TaskSet<T> for tokio::task::JoinSet<T> {
+    type Executor = tokio::runtime::Runtime;
+
+    fn spawn(
+        &mut self,
+        executor: &Self::Executor,
+        future: impl Future<Output = T> + Send + 'static,
+    ) {
+        self.spawn_on(future, executor.handle());
+    }
+
+    async fn join_next(&mut self) -> Option<Result<T, JoinError>> {
+        self.join_next()
+            .await
+            .map(|output| output.map_err(join_error))
+    }
+
+    fn abort_all(&mut self) {
+        self.abort_all();
+        self.detach_all();
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl RuntimeExecutor for Tokio {
+    type Executor = tokio::runtime::Runtime;
+}
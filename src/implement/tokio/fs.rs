@@ -140,6 +140,8 @@ impl File for Compat<tokio::fs::File> {
 }
 
 impl OpenOptions for tokio::fs::OpenOptions {
+    type File = Compat<tokio::fs::File>;
+
     fn new() -> Self {
         Self::new()
     }
@@ -161,10 +163,190 @@ impl OpenOptions for tokio::fs::OpenOptions {
     fn create_new(&mut self, create_new: bool) -> &mut Self {
         self.create_new(create_new)
     }
-    async fn open(
-        &self,
-        path: impl AsRef<std::path::Path>,
-    ) -> std::io::Result<impl File<OpenOptions = Self>> {
+    async fn open(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<Self::File> {
         Ok(self.open(path).await?.compat())
     }
 }
+
+impl SendFs for crate::runtime::Tokio {
+    type File = Compat<tokio::fs::File>;
+    type DirEntry = tokio::fs::DirEntry;
+
+    fn canonocalize(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<std::path::PathBuf>> + Send {
+        <Self as Fs>::canonocalize(path)
+    }
+    fn copy(
+        from: impl AsRef<std::path::Path> + Send,
+        to: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<u64>> + Send {
+        <Self as Fs>::copy(from, to)
+    }
+    fn create_dir(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as Fs>::create_dir(path)
+    }
+    fn create_dir_all(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as Fs>::create_dir_all(path)
+    }
+    fn hard_link(
+        target: impl AsRef<std::path::Path> + Send,
+        link: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as Fs>::hard_link(target, link)
+    }
+    fn metadata(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<std::fs::Metadata>> + Send {
+        <Self as Fs>::metadata(path)
+    }
+    fn read(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Vec<u8>>> + Send {
+        <Self as Fs>::read(path)
+    }
+    fn read_dir(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<
+        Output = std::io::Result<impl Stream<Item = std::io::Result<Self::DirEntry>> + Send>,
+    > + Send {
+        <Self as Fs>::read_dir(path)
+    }
+    fn read_link(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<std::path::PathBuf>> + Send {
+        <Self as Fs>::read_link(path)
+    }
+    fn read_to_string(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<String>> + Send {
+        <Self as Fs>::read_to_string(path)
+    }
+    fn remove_dir(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as Fs>::remove_dir(path)
+    }
+    fn remove_dir_all(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as Fs>::remove_dir_all(path)
+    }
+    fn remove_file(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as Fs>::remove_file(path)
+    }
+    fn rename(
+        from: impl AsRef<std::path::Path> + Send,
+        to: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as Fs>::rename(from, to)
+    }
+    fn set_permissions(
+        path: impl AsRef<std::path::Path> + Send,
+        perm: std::fs::Permissions,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as Fs>::set_permissions(path, perm)
+    }
+    fn symlink_metadata(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<std::fs::Metadata>> + Send {
+        <Self as Fs>::symlink_metadata(path)
+    }
+    fn write(
+        path: impl AsRef<std::path::Path> + Send,
+        contents: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as Fs>::write(path, contents)
+    }
+}
+
+impl SendDirEntry for tokio::fs::DirEntry {
+    fn file_name(&self) -> std::ffi::OsString {
+        <Self as DirEntry>::file_name(self)
+    }
+    fn file_type(&self) -> impl Future<Output = std::io::Result<std::fs::FileType>> + Send {
+        <Self as DirEntry>::file_type(self)
+    }
+    fn metadata(&self) -> impl Future<Output = std::io::Result<std::fs::Metadata>> + Send {
+        <Self as DirEntry>::metadata(self)
+    }
+    fn path(&self) -> std::path::PathBuf {
+        <Self as DirEntry>::path(self)
+    }
+}
+
+impl SendFile for Compat<tokio::fs::File> {
+    type OpenOptions = tokio::fs::OpenOptions;
+
+    fn create(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send {
+        <Self as File>::create(path)
+    }
+    fn create_new(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send {
+        <Self as File>::create_new(path)
+    }
+    fn open(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send {
+        <Self as File>::open(path)
+    }
+    fn set_len(&self, len: u64) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as File>::set_len(self, len)
+    }
+    fn set_permissions(
+        &self,
+        perm: std::fs::Permissions,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as File>::set_permissions(self, perm)
+    }
+    fn sync_all(&self) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as File>::sync_all(self)
+    }
+    fn sync_data(&self) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as File>::sync_data(self)
+    }
+    fn metadata(&self) -> impl Future<Output = std::io::Result<std::fs::Metadata>> + Send {
+        <Self as File>::metadata(self)
+    }
+}
+
+impl SendOpenOptions for tokio::fs::OpenOptions {
+    type File = Compat<tokio::fs::File>;
+
+    fn new() -> Self {
+        <Self as OpenOptions>::new()
+    }
+    fn read(&mut self, read: bool) -> &mut Self {
+        <Self as OpenOptions>::read(self, read)
+    }
+    fn write(&mut self, write: bool) -> &mut Self {
+        <Self as OpenOptions>::write(self, write)
+    }
+    fn append(&mut self, append: bool) -> &mut Self {
+        <Self as OpenOptions>::append(self, append)
+    }
+    fn truncate(&mut self, truncate: bool) -> &mut Self {
+        <Self as OpenOptions>::truncate(self, truncate)
+    }
+    fn create(&mut self, create: bool) -> &mut Self {
+        <Self as OpenOptions>::create(self, create)
+    }
+    fn create_new(&mut self, create_new: bool) -> &mut Self {
+        <Self as OpenOptions>::create_new(self, create_new)
+    }
+    fn open(
+        &self,
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Self::File>> + Send {
+        <Self as OpenOptions>::open(self, path)
+    }
+}
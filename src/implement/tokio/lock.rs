@@ -154,6 +154,127 @@ impl SemaphorePermit for tokio::sync::SemaphorePermit<'_> {
     }
 }
 
+impl<T: ?Sized + Send> SendMutex<T> for tokio::sync::Mutex<T> {
+    type Guard<'a>
+        = <Self as Mutex<T>>::Guard<'a>
+    where
+        Self: 'a;
+
+    fn new(t: T) -> Self
+    where
+        T: Sized,
+    {
+        <Self as Mutex<T>>::new(t)
+    }
+
+    fn lock(&self) -> impl Future<Output = Self::Guard<'_>> + Send {
+        <Self as Mutex<T>>::lock(self)
+    }
+
+    fn try_lock(&self) -> Option<Self::Guard<'_>> {
+        <Self as Mutex<T>>::try_lock(self)
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        <Self as Mutex<T>>::get_mut(self)
+    }
+
+    fn into_inner(self) -> T
+    where
+        T: Sized,
+    {
+        <Self as Mutex<T>>::into_inner(self)
+    }
+}
+
+impl<T: ?Sized + Send + Sync> SendRwLock<T> for tokio::sync::RwLock<T> {
+    type ReadGuard<'a>
+        = <Self as RwLock<T>>::ReadGuard<'a>
+    where
+        Self: 'a;
+    type WriteGuard<'a>
+        = <Self as RwLock<T>>::WriteGuard<'a>
+    where
+        Self: 'a;
+
+    fn new(t: T) -> Self
+    where
+        T: Sized,
+    {
+        <Self as RwLock<T>>::new(t)
+    }
+
+    fn read(&self) -> impl Future<Output = Self::ReadGuard<'_>> + Send {
+        <Self as RwLock<T>>::read(self)
+    }
+
+    fn blocking_read(&self) -> Self::ReadGuard<'_> {
+        <Self as RwLock<T>>::blocking_read(self)
+    }
+
+    fn try_read(&self) -> Option<Self::ReadGuard<'_>> {
+        <Self as RwLock<T>>::try_read(self)
+    }
+
+    fn write(&self) -> impl Future<Output = Self::WriteGuard<'_>> + Send {
+        <Self as RwLock<T>>::write(self)
+    }
+
+    fn blocking_write(&self) -> Self::WriteGuard<'_> {
+        <Self as RwLock<T>>::blocking_write(self)
+    }
+
+    fn try_write(&self) -> Option<Self::WriteGuard<'_>> {
+        <Self as RwLock<T>>::try_write(self)
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        <Self as RwLock<T>>::get_mut(self)
+    }
+
+    fn into_inner(self) -> T
+    where
+        T: Sized,
+    {
+        <Self as RwLock<T>>::into_inner(self)
+    }
+}
+
+impl SendBarrier for tokio::sync::Barrier {
+    type WaitResult = <Self as Barrier>::WaitResult;
+
+    fn new(n: usize) -> Self {
+        <Self as Barrier>::new(n)
+    }
+
+    fn wait(&self) -> impl Future<Output = Self::WaitResult> + Send {
+        <Self as Barrier>::wait(self)
+    }
+}
+
+impl SendSemaphore for tokio::sync::Semaphore {
+    type Permit<'a>
+        = <Self as Semaphore>::Permit<'a>
+    where
+        Self: 'a;
+
+    fn new(permits: usize) -> Self {
+        <Self as Semaphore>::new(permits)
+    }
+
+    fn add_permits(&self, n: usize) {
+        <Self as Semaphore>::add_permits(self, n)
+    }
+
+    fn acquire(&self) -> impl Future<Output = Option<Self::Permit<'_>>> + Send {
+        <Self as Semaphore>::acquire(self)
+    }
+
+    fn try_acquire(&self) -> Option<Self::Permit<'_>> {
+        <Self as Semaphore>::try_acquire(self)
+    }
+}
+
 impl RuntimeLock for Tokio {
     type Mutex<T: ?Sized> = tokio::sync::Mutex<T>;
 }
@@ -163,3 +284,13 @@ impl RuntimeLockExt for Tokio {
     type Barrier = tokio::sync::Barrier;
     type Semaphore = tokio::sync::Semaphore;
 }
+
+impl SendRuntimeLock for Tokio {
+    type Mutex<T: ?Sized + Send> = tokio::sync::Mutex<T>;
+}
+
+impl SendRuntimeLockExt for Tokio {
+    type RwLock<T: ?Sized + Send + Sync> = tokio::sync::RwLock<T>;
+    type Barrier = tokio::sync::Barrier;
+    type Semaphore = tokio::sync::Semaphore;
+}
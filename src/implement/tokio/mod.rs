@@ -1,4 +1,4 @@
-#[cfg(feature = "exec")]
+#[cfg(any(feature = "exec", feature = "local-exec"))]
 mod executor;
 
 #[cfg(feature = "lock")]
@@ -7,6 +7,9 @@ mod lock;
 #[cfg(feature = "channel")]
 mod channel;
 
+#[cfg(feature = "task")]
+mod task;
+
 #[cfg(feature = "fs")]
 mod fs;
 
@@ -15,3 +18,9 @@ mod time;
 
 #[cfg(feature = "net")]
 mod net;
+
+#[cfg(feature = "process")]
+mod process;
+
+#[cfg(feature = "signal")]
+mod signal;
@@ -3,11 +3,13 @@ use tokio_util::compat::{Compat, TokioAsyncReadCompatExt};
 
 impl TcpStream for Compat<tokio::net::TcpStream> {
     async fn connect(addr: impl ToSocketAddrs) -> std::io::Result<Self> {
-        Ok(
-            tokio::net::TcpStream::connect(Tokio::get_socket_addrs(addr.kind()).await?.as_slice())
+        Ok(tokio::net::TcpStream::connect(
+            <Tokio as RuntimeNet>::get_socket_addrs(addr.kind())
                 .await?
-                .compat(),
+                .as_slice(),
         )
+        .await?
+        .compat())
     }
     fn local_addr(&self) -> std::io::Result<std::net::SocketAddr> {
         self.get_ref().local_addr()
@@ -33,13 +35,20 @@ impl TcpStream for Compat<tokio::net::TcpStream> {
 }
 
 impl TcpListener for tokio::net::TcpListener {
-    async fn accept(&self) -> std::io::Result<(impl TcpStream, std::net::SocketAddr)> {
+    type Stream = Compat<tokio::net::TcpStream>;
+
+    async fn accept(&self) -> std::io::Result<(Self::Stream, std::net::SocketAddr)> {
         self.accept()
             .await
             .map(|(stream, addr)| (stream.compat(), addr))
     }
     async fn bind(addr: impl ToSocketAddrs) -> std::io::Result<Self> {
-        tokio::net::TcpListener::bind(Tokio::get_socket_addrs(addr.kind()).await?.as_slice()).await
+        tokio::net::TcpListener::bind(
+            <Tokio as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+        .await
     }
     fn local_addr(&self) -> std::io::Result<std::net::SocketAddr> {
         self.local_addr()
@@ -54,14 +63,23 @@ impl TcpListener for tokio::net::TcpListener {
 
 impl UdpSocket for tokio::net::UdpSocket {
     async fn bind(addr: impl ToSocketAddrs) -> std::io::Result<Self> {
-        tokio::net::UdpSocket::bind(Tokio::get_socket_addrs(addr.kind()).await?.as_slice()).await
+        tokio::net::UdpSocket::bind(
+            <Tokio as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+        .await
     }
     fn broadcast(&self) -> std::io::Result<bool> {
         self.broadcast()
     }
     async fn connect(&self, addr: impl ToSocketAddrs) -> std::io::Result<()> {
-        self.connect(Tokio::get_socket_addrs(addr.kind()).await?.as_slice())
-            .await
+        self.connect(
+            <Tokio as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+        .await
     }
     fn join_multicast_v4(
         &self,
@@ -128,8 +146,13 @@ impl UdpSocket for tokio::net::UdpSocket {
         self.send(buf)
     }
     async fn send_to(&self, buf: &[u8], addr: impl ToSocketAddrs) -> std::io::Result<usize> {
-        self.send_to(buf, Tokio::get_socket_addrs(addr.kind()).await?.as_slice())
-            .await
+        self.send_to(
+            buf,
+            <Tokio as RuntimeNet>::get_socket_addrs(addr.kind())
+                .await?
+                .as_slice(),
+        )
+        .await
     }
     fn set_broadcast(&self, on: bool) -> std::io::Result<()> {
         self.set_broadcast(on)
@@ -151,10 +174,132 @@ impl UdpSocket for tokio::net::UdpSocket {
     }
 }
 
+impl UnixStream for Compat<tokio::net::UnixStream> {
+    async fn connect(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        Ok(tokio::net::UnixStream::connect(path).await?.compat())
+    }
+    async fn connect_addr(addr: &std::os::unix::net::SocketAddr) -> std::io::Result<Self> {
+        // Tokio interprets a leading NUL byte as a name in the abstract namespace.
+        let path = if let Some(path) = addr.as_pathname() {
+            path.to_path_buf()
+        } else {
+            #[cfg(target_os = "linux")]
+            {
+                use std::os::{linux::net::SocketAddrExt, unix::ffi::OsStringExt};
+                let name = addr.as_abstract_name().ok_or_else(unnamed_addr)?;
+                std::ffi::OsString::from_vec([&[0], name].concat()).into()
+            }
+            #[cfg(not(target_os = "linux"))]
+            return Err(unnamed_addr());
+        };
+        <Self as UnixStream>::connect(path).await
+    }
+    fn pair() -> std::io::Result<(Self, Self)> {
+        tokio::net::UnixStream::pair().map(|(a, b)| (a.compat(), b.compat()))
+    }
+    fn local_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        self.get_ref().local_addr().map(Into::into)
+    }
+    fn peer_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        self.get_ref().peer_addr().map(Into::into)
+    }
+    fn peer_cred(&self) -> std::io::Result<UCred> {
+        self.get_ref().peer_cred().map(|cred| UCred {
+            uid: cred.uid(),
+            gid: cred.gid(),
+            pid: cred.pid(),
+        })
+    }
+}
+
+fn unnamed_addr() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "cannot connect to an unnamed Unix socket address",
+    )
+}
+
+impl UnixListener for tokio::net::UnixListener {
+    type Stream = Compat<tokio::net::UnixStream>;
+
+    async fn accept(&self) -> std::io::Result<(Self::Stream, std::os::unix::net::SocketAddr)> {
+        self.accept()
+            .await
+            .map(|(stream, addr)| (stream.compat(), addr.into()))
+    }
+    fn bind(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        tokio::net::UnixListener::bind(path)
+    }
+    fn bind_addr(addr: &std::os::unix::net::SocketAddr) -> std::io::Result<Self> {
+        let listener = std::os::unix::net::UnixListener::bind_addr(addr)?;
+        listener.set_nonblocking(true)?;
+        tokio::net::UnixListener::from_std(listener)
+    }
+    fn local_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        self.local_addr().map(Into::into)
+    }
+}
+
+impl UnixDatagram for tokio::net::UnixDatagram {
+    fn bind(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        tokio::net::UnixDatagram::bind(path)
+    }
+    fn bind_addr(addr: &std::os::unix::net::SocketAddr) -> std::io::Result<Self> {
+        let socket = std::os::unix::net::UnixDatagram::bind_addr(addr)?;
+        socket.set_nonblocking(true)?;
+        tokio::net::UnixDatagram::from_std(socket)
+    }
+    fn unbound() -> std::io::Result<Self> {
+        tokio::net::UnixDatagram::unbound()
+    }
+    fn pair() -> std::io::Result<(Self, Self)> {
+        tokio::net::UnixDatagram::pair()
+    }
+    fn connect(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        self.connect(path)
+    }
+    fn connect_addr(&self, addr: &std::os::unix::net::SocketAddr) -> std::io::Result<()> {
+        use std::os::fd::AsFd;
+        // Connecting a duplicate of the descriptor connects the underlying socket.
+        std::os::unix::net::UnixDatagram::from(self.as_fd().try_clone_to_owned()?)
+            .connect_addr(addr)
+    }
+    fn local_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        self.local_addr().map(Into::into)
+    }
+    fn peer_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        self.peer_addr().map(Into::into)
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl std::future::Future<Output = std::io::Result<usize>> {
+        self.recv(buf)
+    }
+    async fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> std::io::Result<(usize, std::os::unix::net::SocketAddr)> {
+        self.recv_from(buf)
+            .await
+            .map(|(len, addr)| (len, addr.into()))
+    }
+    fn send(&self, buf: &[u8]) -> impl std::future::Future<Output = std::io::Result<usize>> {
+        self.send(buf)
+    }
+    async fn send_to(
+        &self,
+        buf: &[u8],
+        path: impl AsRef<std::path::Path>,
+    ) -> std::io::Result<usize> {
+        self.send_to(buf, path.as_ref().to_path_buf()).await
+    }
+}
+
 impl RuntimeNet for Tokio {
     type TcpStream = Compat<tokio::net::TcpStream>;
     type TcpListener = tokio::net::TcpListener;
     type UdpSocket = tokio::net::UdpSocket;
+    type UnixStream = Compat<tokio::net::UnixStream>;
+    type UnixListener = tokio::net::UnixListener;
+    type UnixDatagram = tokio::net::UnixDatagram;
 
     async fn get_socket_addrs(
         kind: SocketAddrsKind<'_>,
@@ -181,3 +326,264 @@ impl RuntimeNet for Tokio {
         }
     }
 }
+
+impl SendTcpStream for Compat<tokio::net::TcpStream> {
+    fn connect(
+        addr: impl ToSocketAddrs + Send,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send {
+        <Self as TcpStream>::connect(addr)
+    }
+    fn local_addr(&self) -> std::io::Result<std::net::SocketAddr> {
+        <Self as TcpStream>::local_addr(self)
+    }
+    fn nodelay(&self) -> std::io::Result<bool> {
+        <Self as TcpStream>::nodelay(self)
+    }
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        <Self as TcpStream>::peek(self, buf)
+    }
+    fn peer_addr(&self) -> std::io::Result<std::net::SocketAddr> {
+        <Self as TcpStream>::peer_addr(self)
+    }
+    fn set_nodelay(&self, nodelay: bool) -> std::io::Result<()> {
+        <Self as TcpStream>::set_nodelay(self, nodelay)
+    }
+    fn set_ttl(&self, ttl: u32) -> std::io::Result<()> {
+        <Self as TcpStream>::set_ttl(self, ttl)
+    }
+    fn ttl(&self) -> std::io::Result<u32> {
+        <Self as TcpStream>::ttl(self)
+    }
+}
+
+impl SendTcpListener for tokio::net::TcpListener {
+    type Stream = Compat<tokio::net::TcpStream>;
+
+    fn accept(
+        &self,
+    ) -> impl Future<Output = std::io::Result<(Self::Stream, std::net::SocketAddr)>> + Send {
+        <Self as TcpListener>::accept(self)
+    }
+    fn bind(addr: impl ToSocketAddrs + Send) -> impl Future<Output = std::io::Result<Self>> + Send {
+        <Self as TcpListener>::bind(addr)
+    }
+    fn local_addr(&self) -> std::io::Result<std::net::SocketAddr> {
+        <Self as TcpListener>::local_addr(self)
+    }
+    fn set_ttl(&self, ttl: u32) -> std::io::Result<()> {
+        <Self as TcpListener>::set_ttl(self, ttl)
+    }
+    fn ttl(&self) -> std::io::Result<u32> {
+        <Self as TcpListener>::ttl(self)
+    }
+}
+
+impl SendUdpSocket for tokio::net::UdpSocket {
+    fn bind(addr: impl ToSocketAddrs + Send) -> impl Future<Output = std::io::Result<Self>> + Send {
+        <Self as UdpSocket>::bind(addr)
+    }
+    fn broadcast(&self) -> std::io::Result<bool> {
+        <Self as UdpSocket>::broadcast(self)
+    }
+    fn connect(
+        &self,
+        addr: impl ToSocketAddrs + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        <Self as UdpSocket>::connect(self, addr)
+    }
+    fn join_multicast_v4(
+        &self,
+        multiaddr: std::net::Ipv4Addr,
+        interface: std::net::Ipv4Addr,
+    ) -> std::io::Result<()> {
+        <Self as UdpSocket>::join_multicast_v4(self, multiaddr, interface)
+    }
+    fn join_multicast_v6(
+        &self,
+        multiaddr: &std::net::Ipv6Addr,
+        interface: u32,
+    ) -> std::io::Result<()> {
+        <Self as UdpSocket>::join_multicast_v6(self, multiaddr, interface)
+    }
+    fn leave_multicast_v4(
+        &self,
+        multiaddr: std::net::Ipv4Addr,
+        interface: std::net::Ipv4Addr,
+    ) -> std::io::Result<()> {
+        <Self as UdpSocket>::leave_multicast_v4(self, multiaddr, interface)
+    }
+    fn leave_multicast_v6(
+        &self,
+        multiaddr: &std::net::Ipv6Addr,
+        interface: u32,
+    ) -> std::io::Result<()> {
+        <Self as UdpSocket>::leave_multicast_v6(self, multiaddr, interface)
+    }
+    fn local_addr(&self) -> std::io::Result<std::net::SocketAddr> {
+        <Self as UdpSocket>::local_addr(self)
+    }
+    fn multicast_loop_v4(&self) -> std::io::Result<bool> {
+        <Self as UdpSocket>::multicast_loop_v4(self)
+    }
+    fn multicast_loop_v6(&self) -> std::io::Result<bool> {
+        <Self as UdpSocket>::multicast_loop_v6(self)
+    }
+    fn multicast_ttl_v4(&self) -> std::io::Result<u32> {
+        <Self as UdpSocket>::multicast_ttl_v4(self)
+    }
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        <Self as UdpSocket>::peek(self, buf)
+    }
+    fn peek_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = std::io::Result<(usize, std::net::SocketAddr)>> + Send {
+        <Self as UdpSocket>::peek_from(self, buf)
+    }
+    fn peer_addr(&self) -> std::io::Result<std::net::SocketAddr> {
+        <Self as UdpSocket>::peer_addr(self)
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        <Self as UdpSocket>::recv(self, buf)
+    }
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = std::io::Result<(usize, std::net::SocketAddr)>> + Send {
+        <Self as UdpSocket>::recv_from(self, buf)
+    }
+    fn send(&self, buf: &[u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        <Self as UdpSocket>::send(self, buf)
+    }
+    fn send_to(
+        &self,
+        buf: &[u8],
+        addr: impl ToSocketAddrs + Send,
+    ) -> impl Future<Output = std::io::Result<usize>> + Send {
+        <Self as UdpSocket>::send_to(self, buf, addr)
+    }
+    fn set_broadcast(&self, on: bool) -> std::io::Result<()> {
+        <Self as UdpSocket>::set_broadcast(self, on)
+    }
+    fn set_multicast_loop_v4(&self, on: bool) -> std::io::Result<()> {
+        <Self as UdpSocket>::set_multicast_loop_v4(self, on)
+    }
+    fn set_multicast_loop_v6(&self, on: bool) -> std::io::Result<()> {
+        <Self as UdpSocket>::set_multicast_loop_v6(self, on)
+    }
+    fn set_multicast_ttl_v4(&self, ttl: u32) -> std::io::Result<()> {
+        <Self as UdpSocket>::set_multicast_ttl_v4(self, ttl)
+    }
+    fn set_ttl(&self, ttl: u32) -> std::io::Result<()> {
+        <Self as UdpSocket>::set_ttl(self, ttl)
+    }
+    fn ttl(&self) -> std::io::Result<u32> {
+        <Self as UdpSocket>::ttl(self)
+    }
+}
+
+impl SendUnixStream for Compat<tokio::net::UnixStream> {
+    fn connect(
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send {
+        <Self as UnixStream>::connect(path)
+    }
+    fn connect_addr(
+        addr: &std::os::unix::net::SocketAddr,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send {
+        <Self as UnixStream>::connect_addr(addr)
+    }
+    fn pair() -> std::io::Result<(Self, Self)> {
+        <Self as UnixStream>::pair()
+    }
+    fn local_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        <Self as UnixStream>::local_addr(self)
+    }
+    fn peer_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        <Self as UnixStream>::peer_addr(self)
+    }
+    fn peer_cred(&self) -> std::io::Result<UCred> {
+        <Self as UnixStream>::peer_cred(self)
+    }
+}
+
+impl SendUnixListener for tokio::net::UnixListener {
+    type Stream = Compat<tokio::net::UnixStream>;
+
+    fn accept(
+        &self,
+    ) -> impl Future<Output = std::io::Result<(Self::Stream, std::os::unix::net::SocketAddr)>> + Send
+    {
+        <Self as UnixListener>::accept(self)
+    }
+    fn bind(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        <Self as UnixListener>::bind(path)
+    }
+    fn bind_addr(addr: &std::os::unix::net::SocketAddr) -> std::io::Result<Self> {
+        <Self as UnixListener>::bind_addr(addr)
+    }
+    fn local_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        <Self as UnixListener>::local_addr(self)
+    }
+}
+
+impl SendUnixDatagram for tokio::net::UnixDatagram {
+    fn bind(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        <Self as UnixDatagram>::bind(path)
+    }
+    fn bind_addr(addr: &std::os::unix::net::SocketAddr) -> std::io::Result<Self> {
+        <Self as UnixDatagram>::bind_addr(addr)
+    }
+    fn unbound() -> std::io::Result<Self> {
+        <Self as UnixDatagram>::unbound()
+    }
+    fn pair() -> std::io::Result<(Self, Self)> {
+        <Self as UnixDatagram>::pair()
+    }
+    fn connect(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        <Self as UnixDatagram>::connect(self, path)
+    }
+    fn connect_addr(&self, addr: &std::os::unix::net::SocketAddr) -> std::io::Result<()> {
+        <Self as UnixDatagram>::connect_addr(self, addr)
+    }
+    fn local_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        <Self as UnixDatagram>::local_addr(self)
+    }
+    fn peer_addr(&self) -> std::io::Result<std::os::unix::net::SocketAddr> {
+        <Self as UnixDatagram>::peer_addr(self)
+    }
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        <Self as UnixDatagram>::recv(self, buf)
+    }
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = std::io::Result<(usize, std::os::unix::net::SocketAddr)>> + Send {
+        <Self as UnixDatagram>::recv_from(self, buf)
+    }
+    fn send(&self, buf: &[u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        <Self as UnixDatagram>::send(self, buf)
+    }
+    fn send_to(
+        &self,
+        buf: &[u8],
+        path: impl AsRef<std::path::Path> + Send,
+    ) -> impl Future<Output = std::io::Result<usize>> + Send {
+        <Self as UnixDatagram>::send_to(self, buf, path)
+    }
+}
+
+impl SendRuntimeNet for Tokio {
+    type TcpStream = Compat<tokio::net::TcpStream>;
+    type TcpListener = tokio::net::TcpListener;
+    type UdpSocket = tokio::net::UdpSocket;
+    type UnixStream = Compat<tokio::net::UnixStream>;
+    type UnixListener = tokio::net::UnixListener;
+    type UnixDatagram = tokio::net::UnixDatagram;
+
+    fn get_socket_addrs(
+        kind: SocketAddrsKind<'_>,
+    ) -> impl Future<Output = std::io::Result<Vec<std::net::SocketAddr>>> + Send {
+        <Self as RuntimeNet>::get_socket_addrs(kind)
+    }
+}
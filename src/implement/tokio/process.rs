@@ -0,0 +1,194 @@
+use crate::{process::*, runtime::Tokio};
+use std::{
+    ffi::OsStr,
+    io::Result,
+    path::Path,
+    process::{ExitStatus, Output, Stdio},
+};
+use tokio_util::compat::{Compat, TokioAsyncReadCompatExt, TokioAsyncWriteCompatExt};
+
+impl Command for tokio::process::Command {
+    type Child = tokio::process::Child;
+
+    fn new(program: impl AsRef<OsStr>) -> Self {
+        tokio::process::Command::new(program)
+    }
+    fn arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self {
+        self.arg(arg)
+    }
+    fn args(&mut self, args: impl IntoIterator<Item = impl AsRef<OsStr>>) -> &mut Self {
+        self.args(args)
+    }
+    fn env(&mut self, key: impl AsRef<OsStr>, val: impl AsRef<OsStr>) -> &mut Self {
+        self.env(key, val)
+    }
+    fn envs(
+        &mut self,
+        vars: impl IntoIterator<Item = (impl AsRef<OsStr>, impl AsRef<OsStr>)>,
+    ) -> &mut Self {
+        self.envs(vars)
+    }
+    fn env_remove(&mut self, key: impl AsRef<OsStr>) -> &mut Self {
+        self.env_remove(key)
+    }
+    fn env_clear(&mut self) -> &mut Self {
+        self.env_clear()
+    }
+    fn current_dir(&mut self, dir: impl AsRef<Path>) -> &mut Self {
+        self.current_dir(dir)
+    }
+    fn stdin(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        self.stdin(cfg)
+    }
+    fn stdout(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        self.stdout(cfg)
+    }
+    fn stderr(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        self.stderr(cfg)
+    }
+    fn kill_on_drop(&mut self, kill_on_drop: bool) -> &mut Self {
+        self.kill_on_drop(kill_on_drop)
+    }
+    fn spawn(&mut self) -> Result<Self::Child> {
+        self.spawn()
+    }
+    fn status(&mut self) -> impl Future<Output = Result<ExitStatus>> {
+        self.status()
+    }
+    fn output(&mut self) -> impl Future<Output = Result<Output>> {
+        self.output()
+    }
+}
+
+impl Child for tokio::process::Child {
+    type Stdin = Compat<tokio::process::ChildStdin>;
+    type Stdout = Compat<tokio::process::ChildStdout>;
+    type Stderr = Compat<tokio::process::ChildStderr>;
+
+    fn id(&self) -> Option<u32> {
+        self.id()
+    }
+    fn take_stdin(&mut self) -> Option<Self::Stdin> {
+        self.stdin
+            .take()
+            .map(TokioAsyncWriteCompatExt::compat_write)
+    }
+    fn take_stdout(&mut self) -> Option<Self::Stdout> {
+        self.stdout.take().map(TokioAsyncReadCompatExt::compat)
+    }
+    fn take_stderr(&mut self) -> Option<Self::Stderr> {
+        self.stderr.take().map(TokioAsyncReadCompatExt::compat)
+    }
+    fn start_kill(&mut self) -> Result<()> {
+        self.start_kill()
+    }
+    fn kill(&mut self) -> impl Future<Output = Result<()>> {
+        self.kill()
+    }
+    fn try_wait(&mut self) -> Result<Option<ExitStatus>> {
+        self.try_wait()
+    }
+    fn wait(&mut self) -> impl Future<Output = Result<ExitStatus>> {
+        self.wait()
+    }
+    fn wait_with_output(self) -> impl Future<Output = Result<Output>> {
+        self.wait_with_output()
+    }
+}
+
+impl RuntimeProcess for Tokio {
+    type Command = tokio::process::Command;
+    type Child = tokio::process::Child;
+}
+
+impl SendCommand for tokio::process::Command {
+    type Child = tokio::process::Child;
+
+    fn new(program: impl AsRef<OsStr>) -> Self {
+        <Self as Command>::new(program)
+    }
+    fn arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self {
+        <Self as Command>::arg(self, arg)
+    }
+    fn args(&mut self, args: impl IntoIterator<Item = impl AsRef<OsStr>>) -> &mut Self {
+        <Self as Command>::args(self, args)
+    }
+    fn env(&mut self, key: impl AsRef<OsStr>, val: impl AsRef<OsStr>) -> &mut Self {
+        <Self as Command>::env(self, key, val)
+    }
+    fn envs(
+        &mut self,
+        vars: impl IntoIterator<Item = (impl AsRef<OsStr>, impl AsRef<OsStr>)>,
+    ) -> &mut Self {
+        <Self as Command>::envs(self, vars)
+    }
+    fn env_remove(&mut self, key: impl AsRef<OsStr>) -> &mut Self {
+        <Self as Command>::env_remove(self, key)
+    }
+    fn env_clear(&mut self) -> &mut Self {
+        <Self as Command>::env_clear(self)
+    }
+    fn current_dir(&mut self, dir: impl AsRef<Path>) -> &mut Self {
+        <Self as Command>::current_dir(self, dir)
+    }
+    fn stdin(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        <Self as Command>::stdin(self, cfg)
+    }
+    fn stdout(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        <Self as Command>::stdout(self, cfg)
+    }
+    fn stderr(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        <Self as Command>::stderr(self, cfg)
+    }
+    fn kill_on_drop(&mut self, kill_on_drop: bool) -> &mut Self {
+        <Self as Command>::kill_on_drop(self, kill_on_drop)
+    }
+    fn spawn(&mut self) -> Result<Self::Child> {
+        <Self as Command>::spawn(self)
+    }
+    fn status(&mut self) -> impl Future<Output = Result<ExitStatus>> + Send {
+        <Self as Command>::status(self)
+    }
+    fn output(&mut self) -> impl Future<Output = Result<Output>> + Send {
+        <Self as Command>::output(self)
+    }
+}
+
+impl SendChild for tokio::process::Child {
+    type Stdin = Compat<tokio::process::ChildStdin>;
+    type Stdout = Compat<tokio::process::ChildStdout>;
+    type Stderr = Compat<tokio::process::ChildStderr>;
+
+    fn id(&self) -> Option<u32> {
+        <Self as Child>::id(self)
+    }
+    fn take_stdin(&mut self) -> Option<Self::Stdin> {
+        <Self as Child>::take_stdin(self)
+    }
+    fn take_stdout(&mut self) -> Option<Self::Stdout> {
+        <Self as Child>::take_stdout(self)
+    }
+    fn take_stderr(&mut self) -> Option<Self::Stderr> {
+        <Self as Child>::take_stderr(self)
+    }
+    fn start_kill(&mut self) -> Result<()> {
+        <Self as Child>::start_kill(self)
+    }
+    fn kill(&mut self) -> impl Future<Output = Result<()>> + Send {
+        <Self as Child>::kill(self)
+    }
+    fn try_wait(&mut self) -> Result<Option<ExitStatus>> {
+        <Self as Child>::try_wait(self)
+    }
+    fn wait(&mut self) -> impl Future<Output = Result<ExitStatus>> + Send {
+        <Self as Child>::wait(self)
+    }
+    fn wait_with_output(self) -> impl Future<Output = Result<Output>> + Send {
+        <Self as Child>::wait_with_output(self)
+    }
+}
+
+impl SendRuntimeProcess for Tokio {
+    type Command = tokio::process::Command;
+    type Child = tokio::process::Child;
+}
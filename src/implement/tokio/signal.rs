@@ -0,0 +1,43 @@
+use crate::{runtime::Tokio, signal::*};
+use std::io::Result;
+use tokio::signal::unix::{self, SignalKind as TokioSignalKind};
+use tokio_stream::wrappers::SignalStream;
+
+impl Signal for SignalStream {}
+
+impl RuntimeSignal for Tokio {
+    type Signal = SignalStream;
+
+    fn ctrl_c() -> impl Future<Output = Result<()>> {
+        tokio::signal::ctrl_c()
+    }
+    fn signal(kind: SignalKind) -> Result<Self::Signal> {
+        let kind = match kind {
+            SignalKind::Alarm => TokioSignalKind::alarm(),
+            SignalKind::Child => TokioSignalKind::child(),
+            SignalKind::Hangup => TokioSignalKind::hangup(),
+            SignalKind::Interrupt => TokioSignalKind::interrupt(),
+            SignalKind::Io => TokioSignalKind::io(),
+            SignalKind::Pipe => TokioSignalKind::pipe(),
+            SignalKind::Quit => TokioSignalKind::quit(),
+            SignalKind::Terminate => TokioSignalKind::terminate(),
+            SignalKind::UserDefined1 => TokioSignalKind::user_defined1(),
+            SignalKind::UserDefined2 => TokioSignalKind::user_defined2(),
+            SignalKind::WindowChange => TokioSignalKind::window_change(),
+        };
+        unix::signal(kind).map(SignalStream::new)
+    }
+}
+
+impl SendSignal for SignalStream {}
+
+impl SendRuntimeSignal for Tokio {
+    type Signal = SignalStream;
+
+    fn ctrl_c() -> impl Future<Output = Result<()>> + Send {
+        <Self as RuntimeSignal>::ctrl_c()
+    }
+    fn signal(kind: SignalKind) -> Result<Self::Signal> {
+        <Self as RuntimeSignal>::signal(kind)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{runtime::Tokio, task::*};
+
+impl RuntimeTask for Tokio {
+    fn yield_now() -> impl Future<Output = ()> {
+        tokio::task::yield_now()
+    }
+
+    fn consume_budget() -> impl Future<Output = ()> {
+        crate::task::consume_budget(tokio::task::consume_budget())
+    }
+}
+
+impl SendRuntimeTask for Tokio {
+    fn yield_now() -> impl Future<Output = ()> + Send {
+        <Self as RuntimeTask>::yield_now()
+    }
+
+    fn consume_budget() -> impl Future<Output = ()> + Send {
+        <Self as RuntimeTask>::consume_budget()
+    }
+}
@@ -1,21 +1,133 @@
-use crate::{runtime::Tokio, time::Time};
-use futures_lite::StreamExt;
-use std::time::{Duration, Instant};
-use tokio_stream::wrappers::IntervalStream;
+use crate::{
+    runtime::Tokio,
+    time::{self, Elapsed, MissedTickBehavior, SendTime, Time},
+};
+use futures_lite::Stream;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+pub struct Sleep(Pin<Box<tokio::time::Sleep>>);
+
+impl Future for Sleep {
+    type Output = Instant;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0
+            .as_mut()
+            .poll(cx)
+            .map(|()| self.0.deadline().into_std())
+    }
+}
+
+impl time::Sleep for Sleep {
+    fn reset(&mut self, deadline: Instant) {
+        self.0.as_mut().reset(deadline.into());
+    }
+
+    fn deadline(&self) -> Instant {
+        self.0.deadline().into_std()
+    }
+
+    fn is_elapsed(&self) -> bool {
+        self.0.is_elapsed()
+    }
+}
+
+pub struct Interval(tokio::time::Interval);
+
+impl Stream for Interval {
+    type Item = Instant;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_tick(cx).map(|tick| Some(tick.into_std()))
+    }
+}
+
+impl time::Interval for Interval {
+    fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        match self.0.missed_tick_behavior() {
+            tokio::time::MissedTickBehavior::Burst => MissedTickBehavior::Burst,
+            tokio::time::MissedTickBehavior::Delay => MissedTickBehavior::Delay,
+            tokio::time::MissedTickBehavior::Skip => MissedTickBehavior::Skip,
+        }
+    }
+
+    fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.0.set_missed_tick_behavior(match behavior {
+            MissedTickBehavior::Burst => tokio::time::MissedTickBehavior::Burst,
+            MissedTickBehavior::Delay => tokio::time::MissedTickBehavior::Delay,
+            MissedTickBehavior::Skip => tokio::time::MissedTickBehavior::Skip,
+        });
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    fn reset_at(&mut self, deadline: Instant) {
+        self.0.reset_at(deadline.into());
+    }
+
+    fn period(&self) -> Duration {
+        self.0.period()
+    }
+}
 
 impl Time for Tokio {
-    async fn sleep(duration: Duration) -> Instant {
-        tokio::time::sleep(duration).await;
-        Instant::now()
+    type Sleep = Sleep;
+
+    fn sleep(duration: Duration) -> Self::Sleep {
+        Sleep(Box::pin(tokio::time::sleep(duration)))
+    }
+    fn sleep_until(deadline: Instant) -> Self::Sleep {
+        Sleep(Box::pin(tokio::time::sleep_until(deadline.into())))
+    }
+    fn interval(period: Duration) -> impl time::Interval {
+        Interval(tokio::time::interval(period))
+    }
+    fn interval_at(start: Instant, period: Duration) -> impl time::Interval {
+        Interval(tokio::time::interval_at(start.into(), period))
+    }
+    async fn timeout<F: Future>(duration: Duration, future: F) -> Result<F::Output, Elapsed> {
+        tokio::time::timeout(duration, future)
+            .await
+            .map_err(|_| Elapsed::new())
+    }
+    async fn timeout_at<F: Future>(deadline: Instant, future: F) -> Result<F::Output, Elapsed> {
+        tokio::time::timeout_at(deadline.into(), future)
+            .await
+            .map_err(|_| Elapsed::new())
+    }
+}
+
+impl SendTime for Tokio {
+    type Sleep = Sleep;
+
+    fn sleep(duration: Duration) -> Self::Sleep {
+        <Self as Time>::sleep(duration)
+    }
+    fn sleep_until(deadline: Instant) -> Self::Sleep {
+        <Self as Time>::sleep_until(deadline)
+    }
+    fn interval(period: Duration) -> impl time::Interval + Send {
+        <Self as Time>::interval(period)
     }
-    async fn sleep_until(deadline: Instant) -> Instant {
-        tokio::time::sleep_until(deadline.into()).await;
-        Instant::now()
+    fn interval_at(start: Instant, period: Duration) -> impl time::Interval + Send {
+        <Self as Time>::interval_at(start, period)
     }
-    fn interval(duration: Duration) -> impl futures::Stream<Item = Instant> {
-        IntervalStream::new(tokio::time::interval(duration)).map(Into::into)
+    fn timeout<F: Future + Send>(
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send {
+        <Self as Time>::timeout(duration, future)
     }
-    fn interval_at(start: Instant, duration: Duration) -> impl futures::Stream<Item = Instant> {
-        IntervalStream::new(tokio::time::interval_at(start.into(), duration)).map(Into::into)
+    fn timeout_at<F: Future + Send>(
+        deadline: Instant,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send {
+        <Self as Time>::timeout_at(deadline, future)
     }
 }
@@ -16,6 +16,9 @@ pub mod channel;
 #[cfg(any(feature = "exec", feature = "local-exec"))]
 pub mod executor;
 
+#[cfg(feature = "task")]
+pub mod task;
+
 #[cfg(feature = "fs")]
 pub mod fs;
 
@@ -25,7 +28,13 @@ pub mod time;
 #[cfg(feature = "net")]
 pub mod net;
 
-#[cfg(any(feature = "tokio", feature = "smol", feature = "futures"))]
+#[cfg(feature = "process")]
+pub mod process;
+
+#[cfg(feature = "signal")]
+pub mod signal;
+
 pub mod runtime;
+pub use runtime::{Runtime, RuntimeFull, SendRuntime, SendRuntimeFull};
 
 mod implement;
@@ -212,3 +212,124 @@ pub trait RuntimeLockExt: RuntimeLock {
     type Barrier: Barrier;
     type Semaphore: Semaphore;
 }
+
+/// A [`Mutex`] that can be shared between threads and whose futures are [`Send`].
+///
+/// See [`Mutex`] for the documentation of each method.
+pub trait SendMutex<T: ?Sized>: Send + Sync {
+    /// See [`Mutex::Guard`].
+    type Guard<'a>: Send
+    where
+        Self: 'a;
+
+    /// See [`Mutex::new`].
+    fn new(t: T) -> Self
+    where
+        T: Sized;
+
+    /// See [`Mutex::lock`].
+    fn lock(&self) -> impl Future<Output = Self::Guard<'_>> + Send;
+
+    /// See [`Mutex::try_lock`].
+    fn try_lock(&self) -> Option<Self::Guard<'_>>;
+
+    /// See [`Mutex::get_mut`].
+    fn get_mut(&mut self) -> &mut T;
+
+    /// See [`Mutex::into_inner`].
+    fn into_inner(self) -> T
+    where
+        T: Sized;
+}
+
+/// A [`RwLock`] that can be shared between threads and whose futures are [`Send`].
+///
+/// See [`RwLock`] for the documentation of each method.
+pub trait SendRwLock<T: ?Sized>: Send + Sync {
+    /// See [`RwLock::ReadGuard`].
+    type ReadGuard<'a>: RwLockReadGuard<T> + Send
+    where
+        Self: 'a;
+    /// See [`RwLock::WriteGuard`].
+    type WriteGuard<'a>: RwLockWriteGuard<T> + Send
+    where
+        Self: 'a;
+
+    /// See [`RwLock::new`].
+    fn new(t: T) -> Self
+    where
+        T: Sized;
+
+    /// See [`RwLock::read`].
+    fn read(&self) -> impl Future<Output = Self::ReadGuard<'_>> + Send;
+
+    /// See [`RwLock::blocking_read`].
+    fn blocking_read(&self) -> Self::ReadGuard<'_>;
+
+    /// See [`RwLock::try_read`].
+    fn try_read(&self) -> Option<Self::ReadGuard<'_>>;
+
+    /// See [`RwLock::write`].
+    fn write(&self) -> impl Future<Output = Self::WriteGuard<'_>> + Send;
+
+    /// See [`RwLock::blocking_write`].
+    fn blocking_write(&self) -> Self::WriteGuard<'_>;
+
+    /// See [`RwLock::try_write`].
+    fn try_write(&self) -> Option<Self::WriteGuard<'_>>;
+
+    /// See [`RwLock::get_mut`].
+    fn get_mut(&mut self) -> &mut T;
+
+    /// See [`RwLock::into_inner`].
+    fn into_inner(self) -> T
+    where
+        T: Sized;
+}
+
+/// A [`Barrier`] that can be shared between threads and whose futures are [`Send`].
+///
+/// See [`Barrier`] for the documentation of each method.
+pub trait SendBarrier: Send + Sync {
+    type WaitResult: BarrierWaitResult + Send;
+
+    /// See [`Barrier::new`].
+    fn new(n: usize) -> Self;
+
+    /// See [`Barrier::wait`].
+    fn wait(&self) -> impl Future<Output = Self::WaitResult> + Send;
+}
+
+/// A [`Semaphore`] that can be shared between threads and whose futures are [`Send`].
+///
+/// See [`Semaphore`] for the documentation of each method.
+pub trait SendSemaphore: Send + Sync {
+    /// See [`Semaphore::Permit`].
+    type Permit<'a>: SemaphorePermit + Send
+    where
+        Self: 'a;
+
+    /// See [`Semaphore::new`].
+    fn new(permits: usize) -> Self;
+
+    /// See [`Semaphore::add_permits`].
+    fn add_permits(&self, n: usize);
+
+    /// See [`Semaphore::acquire`].
+    fn acquire(&self) -> impl Future<Output = Option<Self::Permit<'_>>> + Send;
+
+    /// See [`Semaphore::try_acquire`].
+    fn try_acquire(&self) -> Option<Self::Permit<'_>>;
+}
+
+/// A runtime with a [`SendMutex`] implementation.
+pub trait SendRuntimeLock {
+    type Mutex<T: ?Sized + Send>: SendMutex<T> + ?Sized;
+}
+
+/// A runtime with more [`Send`] locks.
+pub trait SendRuntimeLockExt: SendRuntimeLock {
+    type RwLock<T: ?Sized + Send + Sync>: SendRwLock<T> + ?Sized;
+    type Barrier: SendBarrier;
+    type Semaphore: SendSemaphore;
+}
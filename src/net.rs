@@ -1,10 +1,14 @@
-//! TCP/UDP networking.
+//! TCP/UDP and Unix domain socket networking.
 
 use futures_lite::{AsyncRead, AsyncWrite};
 use std::{
     io::Result,
     net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
-    os::fd::{AsFd, AsRawFd},
+    os::{
+        fd::{AsFd, AsRawFd},
+        unix::net::SocketAddr as UnixSocketAddr,
+    },
+    path::Path,
 };
 
 /// A type that can be converted to [`SocketAddr`]s.
@@ -130,11 +134,14 @@ pub trait TcpStream: AsyncRead + AsyncWrite + AsRawFd + Sized {
 ///
 /// You can accept a new connection with [`accept`](TcpListener::accept).
 pub trait TcpListener: AsFd + AsRawFd + Sized {
+    /// The stream type of accepted connections.
+    type Stream: TcpStream;
+
     /// Accepts a new incoming connection.
     ///
     /// This function will yield once a new TCP connection is established. When established,
     /// the corresponding [`TcpStream`] and the remote peer's address will be returned.
-    fn accept(&self) -> impl Future<Output = Result<(impl TcpStream, SocketAddr)>>;
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, SocketAddr)>>;
 
     /// Creates a new `TcpListener`, which will be bound to the specified address.
     ///
@@ -301,11 +308,386 @@ pub trait UdpSocket: AsFd + AsRawFd + Sized {
     fn ttl(&self) -> Result<u32>;
 }
 
+/// Credentials of the process on the other end of a [`UnixStream`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UCred {
+    /// The effective user ID of the peer process.
+    pub uid: u32,
+    /// The effective group ID of the peer process.
+    pub gid: u32,
+    /// The process ID of the peer process, if the platform reports it.
+    pub pid: Option<i32>,
+}
+
+/// A Unix domain socket stream between a local and a remote socket.
+///
+/// A Unix stream can either be created by connecting to an endpoint with
+/// [`connect`](UnixStream::connect) or by accepting a connection from a
+/// [listener](UnixListener).
+pub trait UnixStream: AsyncRead + AsyncWrite + AsRawFd + Sized {
+    /// Connects to the socket named by `path`.
+    fn connect(path: impl AsRef<Path>) -> impl Future<Output = Result<Self>>;
+
+    /// Connects to the socket at `addr`.
+    ///
+    /// Unlike [`connect`](UnixStream::connect), this can connect to a socket in the abstract
+    /// namespace on Linux (see
+    /// [`SocketAddrExt::from_abstract_name`](std::os::linux::net::SocketAddrExt::from_abstract_name)).
+    fn connect_addr(addr: &UnixSocketAddr) -> impl Future<Output = Result<Self>>;
+
+    /// Creates an unnamed pair of connected sockets.
+    fn pair() -> Result<(Self, Self)>;
+
+    /// Returns the local address to which this stream is bound.
+    fn local_addr(&self) -> Result<UnixSocketAddr>;
+
+    /// Returns the address of the remote half of this connection.
+    fn peer_addr(&self) -> Result<UnixSocketAddr>;
+
+    /// Returns the effective credentials of the process which called `connect` or `pair`.
+    ///
+    /// Smol's and futures' implementations are only supported on Linux and Android, and will return an
+    /// [`Unsupported`](std::io::ErrorKind::Unsupported) error elsewhere.
+    fn peer_cred(&self) -> Result<UCred>;
+}
+
+/// A Unix domain socket server, listening for connections.
+///
+/// You can accept a new connection with [`accept`](UnixListener::accept).
+pub trait UnixListener: AsFd + AsRawFd + Sized {
+    /// The stream type of accepted connections.
+    type Stream: UnixStream;
+
+    /// Accepts a new incoming connection.
+    ///
+    /// This function will yield once a new connection is established. When established, the
+    /// corresponding [`UnixStream`] and the remote peer's address will be returned.
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, UnixSocketAddr)>>;
+
+    /// Creates a new `UnixListener` bound to the specified path.
+    fn bind(path: impl AsRef<Path>) -> Result<Self>;
+
+    /// Creates a new `UnixListener` bound to the specified address.
+    ///
+    /// Unlike [`bind`](UnixListener::bind), this can bind to a socket in the abstract namespace
+    /// on Linux (see
+    /// [`SocketAddrExt::from_abstract_name`](std::os::linux::net::SocketAddrExt::from_abstract_name)).
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self>;
+
+    /// Returns the local address to which this listener is bound.
+    fn local_addr(&self) -> Result<UnixSocketAddr>;
+}
+
+/// A Unix domain datagram socket.
+///
+/// Like a [`UdpSocket`], a `UnixDatagram` can either communicate with many different peers using
+/// [`send_to`](UnixDatagram::send_to) and [`recv_from`](UnixDatagram::recv_from), or be
+/// [`connect`](UnixDatagram::connect)ed to a single peer and use [`send`](UnixDatagram::send)
+/// and [`recv`](UnixDatagram::recv).
+pub trait UnixDatagram: AsRawFd + Sized {
+    /// Creates a new `UnixDatagram` bound to the specified path.
+    fn bind(path: impl AsRef<Path>) -> Result<Self>;
+
+    /// Creates a new `UnixDatagram` bound to the specified address.
+    ///
+    /// Unlike [`bind`](UnixDatagram::bind), this can bind to a socket in the abstract namespace
+    /// on Linux (see
+    /// [`SocketAddrExt::from_abstract_name`](std::os::linux::net::SocketAddrExt::from_abstract_name)).
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self>;
+
+    /// Creates a new `UnixDatagram` which is not bound to any address.
+    fn unbound() -> Result<Self>;
+
+    /// Creates an unnamed pair of connected sockets.
+    fn pair() -> Result<(Self, Self)>;
+
+    /// Connects the socket to the specified path, setting the default destination for
+    /// [`send`](UnixDatagram::send) and limiting packets that are read with
+    /// [`recv`](UnixDatagram::recv) to those received from `path`.
+    fn connect(&self, path: impl AsRef<Path>) -> Result<()>;
+
+    /// Connects the socket to the specified address.
+    ///
+    /// Unlike [`connect`](UnixDatagram::connect), this can connect to a socket in the abstract
+    /// namespace on Linux (see
+    /// [`SocketAddrExt::from_abstract_name`](std::os::linux::net::SocketAddrExt::from_abstract_name)).
+    fn connect_addr(&self, addr: &UnixSocketAddr) -> Result<()>;
+
+    /// Returns the local address to which this socket is bound.
+    fn local_addr(&self) -> Result<UnixSocketAddr>;
+
+    /// Returns the address of this socket's peer.
+    ///
+    /// This method will fail if the socket is not [`connect`](UnixDatagram::connect)ed.
+    fn peer_addr(&self) -> Result<UnixSocketAddr>;
+
+    /// Receives a single datagram message on the socket from the address to which it is
+    /// connected. On success, returns the number of bytes read.
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>>;
+
+    /// Receives a single datagram message on the socket. On success, returns the number of
+    /// bytes read and the origin.
+    fn recv_from(&self, buf: &mut [u8]) -> impl Future<Output = Result<(usize, UnixSocketAddr)>>;
+
+    /// Sends data on the socket to the address to which it is connected. On success, returns
+    /// the number of bytes written.
+    ///
+    /// This method will fail if the socket is not [`connect`](UnixDatagram::connect)ed.
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>>;
+
+    /// Sends data on the socket to the specified path. On success, returns the number of bytes
+    /// written.
+    fn send_to(&self, buf: &[u8], path: impl AsRef<Path>) -> impl Future<Output = Result<usize>>;
+}
+
 /// A runtime with networking.
 pub trait RuntimeNet {
     type TcpStream: TcpStream;
-    type TcpListener: TcpListener;
+    type TcpListener: TcpListener<Stream = Self::TcpStream>;
     type UdpSocket: UdpSocket;
+    type UnixStream: UnixStream;
+    type UnixListener: UnixListener<Stream = Self::UnixStream>;
+    type UnixDatagram: UnixDatagram;
 
     fn get_socket_addrs(kind: SocketAddrsKind) -> impl Future<Output = Result<Vec<SocketAddr>>>;
 }
+
+/// A [`TcpStream`] that can be sent between threads and whose futures are [`Send`].
+///
+/// See [`TcpStream`] for the documentation of each method.
+pub trait SendTcpStream: AsyncRead + AsyncWrite + AsRawFd + Send + Sized {
+    /// See [`TcpStream::connect`].
+    fn connect(addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<Self>> + Send;
+
+    /// See [`TcpStream::local_addr`].
+    fn local_addr(&self) -> Result<SocketAddr>;
+
+    /// See [`TcpStream::nodelay`].
+    fn nodelay(&self) -> Result<bool>;
+
+    /// See [`TcpStream::peek`].
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send;
+
+    /// See [`TcpStream::peer_addr`].
+    fn peer_addr(&self) -> Result<SocketAddr>;
+
+    /// See [`TcpStream::set_nodelay`].
+    fn set_nodelay(&self, nodelay: bool) -> Result<()>;
+
+    /// See [`TcpStream::set_ttl`].
+    fn set_ttl(&self, ttl: u32) -> Result<()>;
+
+    /// See [`TcpStream::ttl`].
+    fn ttl(&self) -> Result<u32>;
+}
+
+/// A [`TcpListener`] that can be shared between threads and whose futures are [`Send`].
+///
+/// See [`TcpListener`] for the documentation of each method.
+pub trait SendTcpListener: AsFd + AsRawFd + Send + Sync + Sized {
+    /// See [`TcpListener::Stream`].
+    type Stream: SendTcpStream;
+
+    /// See [`TcpListener::accept`].
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, SocketAddr)>> + Send;
+
+    /// See [`TcpListener::bind`].
+    fn bind(addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<Self>> + Send;
+
+    /// See [`TcpListener::local_addr`].
+    fn local_addr(&self) -> Result<SocketAddr>;
+
+    /// See [`TcpListener::set_ttl`].
+    fn set_ttl(&self, ttl: u32) -> Result<()>;
+
+    /// See [`TcpListener::ttl`].
+    fn ttl(&self) -> Result<u32>;
+}
+
+/// A [`UdpSocket`] that can be shared between threads and whose futures are [`Send`].
+///
+/// See [`UdpSocket`] for the documentation of each method.
+pub trait SendUdpSocket: AsFd + AsRawFd + Send + Sync + Sized {
+    /// See [`UdpSocket::bind`].
+    fn bind(addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<Self>> + Send;
+
+    /// See [`UdpSocket::broadcast`].
+    fn broadcast(&self) -> Result<bool>;
+
+    /// See [`UdpSocket::connect`].
+    fn connect(&self, addr: impl ToSocketAddrs + Send) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`UdpSocket::join_multicast_v4`].
+    fn join_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()>;
+
+    /// See [`UdpSocket::join_multicast_v6`].
+    fn join_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()>;
+
+    /// See [`UdpSocket::leave_multicast_v4`].
+    fn leave_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()>;
+
+    /// See [`UdpSocket::leave_multicast_v6`].
+    fn leave_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()>;
+
+    /// See [`UdpSocket::local_addr`].
+    fn local_addr(&self) -> Result<SocketAddr>;
+
+    /// See [`UdpSocket::multicast_loop_v4`].
+    fn multicast_loop_v4(&self) -> Result<bool>;
+
+    /// See [`UdpSocket::multicast_loop_v6`].
+    fn multicast_loop_v6(&self) -> Result<bool>;
+
+    /// See [`UdpSocket::multicast_ttl_v4`].
+    fn multicast_ttl_v4(&self) -> Result<u32>;
+
+    /// See [`UdpSocket::peek`].
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send;
+
+    /// See [`UdpSocket::peek_from`].
+    fn peek_from(&self, buf: &mut [u8])
+    -> impl Future<Output = Result<(usize, SocketAddr)>> + Send;
+
+    /// See [`UdpSocket::peer_addr`].
+    fn peer_addr(&self) -> Result<SocketAddr>;
+
+    /// See [`UdpSocket::recv`].
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send;
+
+    /// See [`UdpSocket::recv_from`].
+    fn recv_from(&self, buf: &mut [u8])
+    -> impl Future<Output = Result<(usize, SocketAddr)>> + Send;
+
+    /// See [`UdpSocket::send`].
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>> + Send;
+
+    /// See [`UdpSocket::send_to`].
+    fn send_to(
+        &self,
+        buf: &[u8],
+        addr: impl ToSocketAddrs + Send,
+    ) -> impl Future<Output = Result<usize>> + Send;
+
+    /// See [`UdpSocket::set_broadcast`].
+    fn set_broadcast(&self, on: bool) -> Result<()>;
+
+    /// See [`UdpSocket::set_multicast_loop_v4`].
+    fn set_multicast_loop_v4(&self, on: bool) -> Result<()>;
+
+    /// See [`UdpSocket::set_multicast_loop_v6`].
+    fn set_multicast_loop_v6(&self, on: bool) -> Result<()>;
+
+    /// See [`UdpSocket::set_multicast_ttl_v4`].
+    fn set_multicast_ttl_v4(&self, ttl: u32) -> Result<()>;
+
+    /// See [`UdpSocket::set_ttl`].
+    fn set_ttl(&self, ttl: u32) -> Result<()>;
+
+    /// See [`UdpSocket::ttl`].
+    fn ttl(&self) -> Result<u32>;
+}
+
+/// A [`UnixStream`] that can be sent between threads and whose futures are [`Send`].
+///
+/// See [`UnixStream`] for the documentation of each method.
+pub trait SendUnixStream: AsyncRead + AsyncWrite + AsRawFd + Send + Sized {
+    /// See [`UnixStream::connect`].
+    fn connect(path: impl AsRef<Path> + Send) -> impl Future<Output = Result<Self>> + Send;
+
+    /// See [`UnixStream::connect_addr`].
+    fn connect_addr(addr: &UnixSocketAddr) -> impl Future<Output = Result<Self>> + Send;
+
+    /// See [`UnixStream::pair`].
+    fn pair() -> Result<(Self, Self)>;
+
+    /// See [`UnixStream::local_addr`].
+    fn local_addr(&self) -> Result<UnixSocketAddr>;
+
+    /// See [`UnixStream::peer_addr`].
+    fn peer_addr(&self) -> Result<UnixSocketAddr>;
+
+    /// See [`UnixStream::peer_cred`].
+    fn peer_cred(&self) -> Result<UCred>;
+}
+
+/// A [`UnixListener`] that can be shared between threads and whose futures are [`Send`].
+///
+/// See [`UnixListener`] for the documentation of each method.
+pub trait SendUnixListener: AsFd + AsRawFd + Send + Sync + Sized {
+    /// See [`UnixListener::Stream`].
+    type Stream: SendUnixStream;
+
+    /// See [`UnixListener::accept`].
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, UnixSocketAddr)>> + Send;
+
+    /// See [`UnixListener::bind`].
+    fn bind(path: impl AsRef<Path>) -> Result<Self>;
+
+    /// See [`UnixListener::bind_addr`].
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self>;
+
+    /// See [`UnixListener::local_addr`].
+    fn local_addr(&self) -> Result<UnixSocketAddr>;
+}
+
+/// A [`UnixDatagram`] that can be shared between threads and whose futures are [`Send`].
+///
+/// See [`UnixDatagram`] for the documentation of each method.
+pub trait SendUnixDatagram: AsRawFd + Send + Sync + Sized {
+    /// See [`UnixDatagram::bind`].
+    fn bind(path: impl AsRef<Path>) -> Result<Self>;
+
+    /// See [`UnixDatagram::bind_addr`].
+    fn bind_addr(addr: &UnixSocketAddr) -> Result<Self>;
+
+    /// See [`UnixDatagram::unbound`].
+    fn unbound() -> Result<Self>;
+
+    /// See [`UnixDatagram::pair`].
+    fn pair() -> Result<(Self, Self)>;
+
+    /// See [`UnixDatagram::connect`].
+    fn connect(&self, path: impl AsRef<Path>) -> Result<()>;
+
+    /// See [`UnixDatagram::connect_addr`].
+    fn connect_addr(&self, addr: &UnixSocketAddr) -> Result<()>;
+
+    /// See [`UnixDatagram::local_addr`].
+    fn local_addr(&self) -> Result<UnixSocketAddr>;
+
+    /// See [`UnixDatagram::peer_addr`].
+    fn peer_addr(&self) -> Result<UnixSocketAddr>;
+
+    /// See [`UnixDatagram::recv`].
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> + Send;
+
+    /// See [`UnixDatagram::recv_from`].
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = Result<(usize, UnixSocketAddr)>> + Send;
+
+    /// See [`UnixDatagram::send`].
+    fn send(&self, buf: &[u8]) -> impl Future<Output = Result<usize>> + Send;
+
+    /// See [`UnixDatagram::send_to`].
+    fn send_to(
+        &self,
+        buf: &[u8],
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<usize>> + Send;
+}
+
+/// A runtime with networking whose futures are [`Send`].
+pub trait SendRuntimeNet {
+    type TcpStream: SendTcpStream;
+    type TcpListener: SendTcpListener<Stream = Self::TcpStream>;
+    type UdpSocket: SendUdpSocket;
+    type UnixStream: SendUnixStream;
+    type UnixListener: SendUnixListener<Stream = Self::UnixStream>;
+    type UnixDatagram: SendUnixDatagram;
+
+    fn get_socket_addrs(
+        kind: SocketAddrsKind<'_>,
+    ) -> impl Future<Output = Result<Vec<SocketAddr>>> + Send;
+}
@@ -0,0 +1,253 @@
+//! Child process spawning.
+//!
+//! This is essentially an async-compatible copy of the [`std::process`] module. See the
+//! documentation there for more details.
+
+use futures_lite::{AsyncRead, AsyncWrite};
+use std::{
+    ffi::OsStr,
+    io::Result,
+    path::Path,
+    process::{ExitStatus, Output, Stdio},
+};
+
+/// A runtime that can spawn child processes.
+pub trait RuntimeProcess {
+    type Command: Command<Child = Self::Child>;
+    type Child: Child;
+}
+
+/// A process builder, providing fine-grained control over how a new process should be spawned.
+///
+/// A default configuration can be generated using [`Command::new`], where `program` gives a path
+/// to the program to be executed. Additional builder methods allow the configuration to be changed
+/// (for example, by adding arguments) prior to spawning.
+pub trait Command: Sized {
+    /// The child process handle returned by [`spawn`](Command::spawn).
+    type Child: Child;
+
+    /// Constructs a new `Command` for launching the program at path `program`, with the following
+    /// default configuration:
+    ///
+    /// - No arguments to the program
+    /// - Inherit the current process's environment
+    /// - Inherit the current process's working directory
+    /// - Inherit stdin/stdout/stderr for [`spawn`](Command::spawn) or
+    ///   [`status`](Command::status), but create pipes for [`output`](Command::output)
+    fn new(program: impl AsRef<OsStr>) -> Self;
+
+    /// Adds an argument to pass to the program.
+    fn arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self;
+
+    /// Adds multiple arguments to pass to the program.
+    fn args(&mut self, args: impl IntoIterator<Item = impl AsRef<OsStr>>) -> &mut Self;
+
+    /// Inserts or updates an environment variable mapping.
+    fn env(&mut self, key: impl AsRef<OsStr>, val: impl AsRef<OsStr>) -> &mut Self;
+
+    /// Inserts or updates multiple environment variable mappings.
+    fn envs(
+        &mut self,
+        vars: impl IntoIterator<Item = (impl AsRef<OsStr>, impl AsRef<OsStr>)>,
+    ) -> &mut Self;
+
+    /// Removes an environment variable mapping.
+    fn env_remove(&mut self, key: impl AsRef<OsStr>) -> &mut Self;
+
+    /// Clears the entire environment map for the child process.
+    fn env_clear(&mut self) -> &mut Self;
+
+    /// Sets the working directory for the child process.
+    fn current_dir(&mut self, dir: impl AsRef<Path>) -> &mut Self;
+
+    /// Configuration for the child process's standard input (stdin) handle.
+    ///
+    /// Use [`Stdio::piped`] to be able to write to the child through [`Child::take_stdin`].
+    fn stdin(&mut self, cfg: impl Into<Stdio>) -> &mut Self;
+
+    /// Configuration for the child process's standard output (stdout) handle.
+    ///
+    /// Use [`Stdio::piped`] to be able to read from the child through [`Child::take_stdout`].
+    fn stdout(&mut self, cfg: impl Into<Stdio>) -> &mut Self;
+
+    /// Configuration for the child process's standard error (stderr) handle.
+    ///
+    /// Use [`Stdio::piped`] to be able to read from the child through [`Child::take_stderr`].
+    fn stderr(&mut self, cfg: impl Into<Stdio>) -> &mut Self;
+
+    /// Controls whether a `kill` operation should be invoked on a spawned child process when its
+    /// corresponding [`Child`] handle is dropped.
+    ///
+    /// By default, this value is `false`, and the child process will keep running after its handle
+    /// is dropped.
+    fn kill_on_drop(&mut self, kill_on_drop: bool) -> &mut Self;
+
+    /// Executes the command as a child process, returning a handle to it.
+    fn spawn(&mut self) -> Result<Self::Child>;
+
+    /// Executes the command as a child process, waiting for it to finish and collecting its exit
+    /// status.
+    fn status(&mut self) -> impl Future<Output = Result<ExitStatus>>;
+
+    /// Executes the command as a child process, waiting for it to finish and collecting all of its
+    /// output.
+    ///
+    /// Unless otherwise configured, stdout and stderr are captured, and stdin is set to
+    /// [`Stdio::null`].
+    fn output(&mut self) -> impl Future<Output = Result<Output>>;
+}
+
+/// A handle to a spawned child process.
+pub trait Child: Sized {
+    /// The handle for writing to the child's stdin.
+    type Stdin: AsyncWrite;
+
+    /// The handle for reading from the child's stdout.
+    type Stdout: AsyncRead;
+
+    /// The handle for reading from the child's stderr.
+    type Stderr: AsyncRead;
+
+    /// Returns the OS-assigned process identifier associated with this child.
+    ///
+    /// Tokio's implementation returns `None` once the child has been polled to completion.
+    fn id(&self) -> Option<u32>;
+
+    /// Takes the handle for writing to the child's stdin, if it was captured and hasn't already
+    /// been taken.
+    fn take_stdin(&mut self) -> Option<Self::Stdin>;
+
+    /// Takes the handle for reading from the child's stdout, if it was captured and hasn't already
+    /// been taken.
+    fn take_stdout(&mut self) -> Option<Self::Stdout>;
+
+    /// Takes the handle for reading from the child's stderr, if it was captured and hasn't already
+    /// been taken.
+    fn take_stderr(&mut self) -> Option<Self::Stderr>;
+
+    /// Forces the child to exit, without waiting for it to do so.
+    ///
+    /// This is equivalent to sending a `SIGKILL` on Unix platforms.
+    fn start_kill(&mut self) -> Result<()>;
+
+    /// Forces the child to exit and waits for it to do so.
+    fn kill(&mut self) -> impl Future<Output = Result<()>>;
+
+    /// Returns the exit status if the child has already exited, without waiting.
+    fn try_wait(&mut self) -> Result<Option<ExitStatus>>;
+
+    /// Waits for the child to exit completely, returning the status that it exited with.
+    ///
+    /// The child's stdin handle, if any, is closed before waiting in order to avoid deadlocks.
+    fn wait(&mut self) -> impl Future<Output = Result<ExitStatus>>;
+
+    /// Waits for the child to exit, collecting its status and any remaining output on stdout and
+    /// stderr.
+    ///
+    /// Only output from handles which were captured and not [taken](Child::take_stdout) is
+    /// collected.
+    fn wait_with_output(self) -> impl Future<Output = Result<Output>>;
+}
+
+/// A runtime that can spawn child processes whose futures are [`Send`].
+pub trait SendRuntimeProcess {
+    type Command: SendCommand<Child = Self::Child>;
+    type Child: SendChild;
+}
+
+/// A [`Command`] that can be sent between threads and whose futures are [`Send`].
+///
+/// See [`Command`] for the documentation of each method.
+pub trait SendCommand: Send + Sized {
+    /// See [`Command::Child`].
+    type Child: SendChild;
+
+    /// See [`Command::new`].
+    fn new(program: impl AsRef<OsStr>) -> Self;
+
+    /// See [`Command::arg`].
+    fn arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self;
+
+    /// See [`Command::args`].
+    fn args(&mut self, args: impl IntoIterator<Item = impl AsRef<OsStr>>) -> &mut Self;
+
+    /// See [`Command::env`].
+    fn env(&mut self, key: impl AsRef<OsStr>, val: impl AsRef<OsStr>) -> &mut Self;
+
+    /// See [`Command::envs`].
+    fn envs(
+        &mut self,
+        vars: impl IntoIterator<Item = (impl AsRef<OsStr>, impl AsRef<OsStr>)>,
+    ) -> &mut Self;
+
+    /// See [`Command::env_remove`].
+    fn env_remove(&mut self, key: impl AsRef<OsStr>) -> &mut Self;
+
+    /// See [`Command::env_clear`].
+    fn env_clear(&mut self) -> &mut Self;
+
+    /// See [`Command::current_dir`].
+    fn current_dir(&mut self, dir: impl AsRef<Path>) -> &mut Self;
+
+    /// See [`Command::stdin`].
+    fn stdin(&mut self, cfg: impl Into<Stdio>) -> &mut Self;
+
+    /// See [`Command::stdout`].
+    fn stdout(&mut self, cfg: impl Into<Stdio>) -> &mut Self;
+
+    /// See [`Command::stderr`].
+    fn stderr(&mut self, cfg: impl Into<Stdio>) -> &mut Self;
+
+    /// See [`Command::kill_on_drop`].
+    fn kill_on_drop(&mut self, kill_on_drop: bool) -> &mut Self;
+
+    /// See [`Command::spawn`].
+    fn spawn(&mut self) -> Result<Self::Child>;
+
+    /// See [`Command::status`].
+    fn status(&mut self) -> impl Future<Output = Result<ExitStatus>> + Send;
+
+    /// See [`Command::output`].
+    fn output(&mut self) -> impl Future<Output = Result<Output>> + Send;
+}
+
+/// A [`Child`] that can be sent between threads and whose futures are [`Send`].
+///
+/// See [`Child`] for the documentation of each method.
+pub trait SendChild: Send + Sized {
+    /// See [`Child::Stdin`].
+    type Stdin: AsyncWrite + Send;
+
+    /// See [`Child::Stdout`].
+    type Stdout: AsyncRead + Send;
+
+    /// See [`Child::Stderr`].
+    type Stderr: AsyncRead + Send;
+
+    /// See [`Child::id`].
+    fn id(&self) -> Option<u32>;
+
+    /// See [`Child::take_stdin`].
+    fn take_stdin(&mut self) -> Option<Self::Stdin>;
+
+    /// See [`Child::take_stdout`].
+    fn take_stdout(&mut self) -> Option<Self::Stdout>;
+
+    /// See [`Child::take_stderr`].
+    fn take_stderr(&mut self) -> Option<Self::Stderr>;
+
+    /// See [`Child::start_kill`].
+    fn start_kill(&mut self) -> Result<()>;
+
+    /// See [`Child::kill`].
+    fn kill(&mut self) -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`Child::try_wait`].
+    fn try_wait(&mut self) -> Result<Option<ExitStatus>>;
+
+    /// See [`Child::wait`].
+    fn wait(&mut self) -> impl Future<Output = Result<ExitStatus>> + Send;
+
+    /// See [`Child::wait_with_output`].
+    fn wait_with_output(self) -> impl Future<Output = Result<Output>> + Send;
+}
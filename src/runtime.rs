@@ -1,4 +1,172 @@
-//! Provided runtime implementations.
+//! Provided runtime implementations, and the [`Runtime`] trait bundling every capability.
+
+/// A runtime implementing every capability enabled by this crate's features.
+///
+/// This is implemented for every type that implements the traits of all enabled modules, so a
+/// library generic over its runtime can simply require `R: Runtime` instead of spelling out each
+/// trait. Note that the set of required traits changes with the enabled features:
+///
+/// | Feature      | Required trait                                                   |
+/// |--------------|------------------------------------------------------------------|
+/// | `lock`       | [`RuntimeLock`](crate::lock::RuntimeLock)                        |
+/// | `channel`    | [`RuntimeMpsc`](crate::channel::mpsc::RuntimeMpsc) and [`RuntimeOneshot`](crate::channel::oneshot::RuntimeOneshot) |
+/// | `exec`       | [`RuntimeExecutor`](crate::executor::RuntimeExecutor)            |
+/// | `local-exec` | [`RuntimeExecutor`](crate::executor::local::RuntimeExecutor)     |
+/// | `task`       | [`RuntimeTask`](crate::task::RuntimeTask)                        |
+/// | `fs`         | [`Fs`](crate::fs::Fs)                                            |
+/// | `time`       | [`Time`](crate::time::Time)                                      |
+/// | `net`        | [`RuntimeNet`](crate::net::RuntimeNet)                           |
+/// | `process`    | [`RuntimeProcess`](crate::process::RuntimeProcess)               |
+/// | `signal`     | [`RuntimeSignal`](crate::signal::RuntimeSignal)                  |
+pub trait Runtime:
+    capability::Lock
+    + capability::Channel
+    + capability::Exec
+    + capability::LocalExec
+    + capability::Task
+    + capability::Fs
+    + capability::Time
+    + capability::Net
+    + capability::Process
+    + capability::Signal
+    + Send
+    + Sync
+    + 'static
+{
+}
+
+impl<R> Runtime for R where
+    R: capability::Lock
+        + capability::Channel
+        + capability::Exec
+        + capability::LocalExec
+        + capability::Task
+        + capability::Fs
+        + capability::Time
+        + capability::Net
+        + capability::Process
+        + capability::Signal
+        + Send
+        + Sync
+        + 'static
+{
+}
+
+/// A [`Runtime`] that additionally implements the optional `*Ext` runtime traits of all enabled
+/// modules.
+///
+/// | Feature | Required trait                                     |
+/// |---------|----------------------------------------------------|
+/// | `lock`  | [`RuntimeLockExt`](crate::lock::RuntimeLockExt)    |
+pub trait RuntimeFull: Runtime + capability::LockExt {}
+
+impl<R> RuntimeFull for R where R: Runtime + capability::LockExt {}
+
+/// A runtime implementing the [`Send`] variant of every capability enabled by this crate's
+/// features.
+///
+/// This is the counterpart of [`Runtime`] for code that needs to move futures between threads,
+/// such as futures passed to [`Executor::spawn`](crate::executor::Executor::spawn). The required
+/// traits are the `Send*` counterparts of those listed on [`Runtime`] (e.g.
+/// [`SendFs`](crate::fs::SendFs) instead of [`Fs`](crate::fs::Fs)), while the executor
+/// requirements are the same.
+///
+/// `SendRuntime` does not imply `Runtime`, since the two traits share method names. Bound on
+/// whichever one you call methods through.
+pub trait SendRuntime:
+    capability::SendLock
+    + capability::SendChannel
+    + capability::Exec
+    + capability::LocalExec
+    + capability::SendTask
+    + capability::SendFs
+    + capability::SendTime
+    + capability::SendNet
+    + capability::SendProcess
+    + capability::SendSignal
+    + Send
+    + Sync
+    + 'static
+{
+}
+
+impl<R> SendRuntime for R where
+    R: capability::SendLock
+        + capability::SendChannel
+        + capability::Exec
+        + capability::LocalExec
+        + capability::SendTask
+        + capability::SendFs
+        + capability::SendTime
+        + capability::SendNet
+        + capability::SendProcess
+        + capability::SendSignal
+        + Send
+        + Sync
+        + 'static
+{
+}
+
+/// A [`SendRuntime`] that additionally implements the [`Send`] variants of the optional `*Ext`
+/// runtime traits of all enabled modules.
+pub trait SendRuntimeFull: SendRuntime + capability::SendLockExt {}
+
+impl<R> SendRuntimeFull for R where R: SendRuntime + capability::SendLockExt {}
+
+/// Per-feature building blocks of [`Runtime`], [`SendRuntime`] and their `*Full` counterparts.
+///
+/// Each trait here is blanket-implemented for every type implementing its bounds when the
+/// corresponding feature is enabled, and for every type at all when it isn't.
+#[doc(hidden)]
+pub mod capability {
+    macro_rules! capability {
+        ($name:ident, $feature:literal, $($bound:tt)+) => {
+            #[cfg(feature = $feature)]
+            pub trait $name: $($bound)+ {}
+            #[cfg(feature = $feature)]
+            impl<R: $($bound)+> $name for R {}
+
+            #[cfg(not(feature = $feature))]
+            pub trait $name {}
+            #[cfg(not(feature = $feature))]
+            impl<R: ?Sized> $name for R {}
+        };
+    }
+
+    capability!(Lock, "lock", crate::lock::RuntimeLock);
+    capability!(LockExt, "lock", crate::lock::RuntimeLockExt);
+    capability!(
+        Channel,
+        "channel",
+        crate::channel::mpsc::RuntimeMpsc + crate::channel::oneshot::RuntimeOneshot
+    );
+    capability!(Exec, "exec", crate::executor::RuntimeExecutor);
+    capability!(
+        LocalExec,
+        "local-exec",
+        crate::executor::local::RuntimeExecutor
+    );
+    capability!(Task, "task", crate::task::RuntimeTask);
+    capability!(Fs, "fs", crate::fs::Fs);
+    capability!(Time, "time", crate::time::Time);
+    capability!(Net, "net", crate::net::RuntimeNet);
+    capability!(Process, "process", crate::process::RuntimeProcess);
+    capability!(Signal, "signal", crate::signal::RuntimeSignal);
+
+    capability!(SendLock, "lock", crate::lock::SendRuntimeLock);
+    capability!(SendLockExt, "lock", crate::lock::SendRuntimeLockExt);
+    capability!(
+        SendChannel,
+        "channel",
+        crate::channel::mpsc::SendRuntimeMpsc + crate::channel::oneshot::SendRuntimeOneshot
+    );
+    capability!(SendTask, "task", crate::task::SendRuntimeTask);
+    capability!(SendFs, "fs", crate::fs::SendFs);
+    capability!(SendTime, "time", crate::time::SendTime);
+    capability!(SendNet, "net", crate::net::SendRuntimeNet);
+    capability!(SendProcess, "process", crate::process::SendRuntimeProcess);
+    capability!(SendSignal, "signal", crate::signal::SendRuntimeSignal);
+}
 
 #[cfg(feature = "tokio")]
 /// [`tokio`]
@@ -0,0 +1,88 @@
+//! OS signal handling.
+
+use futures_lite::{Stream, StreamExt};
+use std::io::Result;
+
+/// A runtime that can listen for OS signals.
+pub trait RuntimeSignal {
+    /// The stream of notifications returned by [`signal`](RuntimeSignal::signal).
+    type Signal: Signal;
+
+    /// Completes when a "ctrl-c" notification (`SIGINT`) is sent to the process.
+    ///
+    /// The first call installs a process-wide handler for `SIGINT`. This handler is never removed,
+    /// so the default behavior of terminating the process will no longer apply afterwards.
+    fn ctrl_c() -> impl Future<Output = Result<()>>;
+
+    /// Creates a stream which yields each time the process receives a signal of the given kind.
+    ///
+    /// As with [`ctrl_c`](RuntimeSignal::ctrl_c), the handler for each kind of signal is installed
+    /// on first use and never removed.
+    fn signal(kind: SignalKind) -> Result<Self::Signal>;
+}
+
+/// A stream of notifications of a Unix signal, created with [`RuntimeSignal::signal`].
+///
+/// Multiple deliveries of a signal between polls may be coalesced into a single notification.
+pub trait Signal: Stream<Item = ()> + Unpin {
+    /// Waits for the next notification of the signal.
+    ///
+    /// Returns `None` if no more notifications can be received.
+    fn recv(&mut self) -> impl Future<Output = Option<()>> {
+        self.next()
+    }
+}
+
+/// A kind of Unix signal that can be listened for with [`RuntimeSignal::signal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SignalKind {
+    /// `SIGALRM`, sent when a real-time timer has expired.
+    Alarm,
+    /// `SIGCHLD`, sent when the status of a child process changes.
+    Child,
+    /// `SIGHUP`, sent when the terminal is disconnected. Often used to request a configuration
+    /// reload.
+    Hangup,
+    /// `SIGINT`, sent when the user interrupts the process (usually with ctrl-c).
+    Interrupt,
+    /// `SIGIO`, sent when I/O operations are possible on some file descriptor.
+    Io,
+    /// `SIGPIPE`, sent when the process writes to a pipe with no reader.
+    Pipe,
+    /// `SIGQUIT`, sent to issue a shutdown of the process, after which the OS will dump the
+    /// process core.
+    Quit,
+    /// `SIGTERM`, sent to issue a shutdown of the process.
+    Terminate,
+    /// `SIGUSR1`, a user defined signal.
+    UserDefined1,
+    /// `SIGUSR2`, a user defined signal.
+    UserDefined2,
+    /// `SIGWINCH`, sent when the terminal window is resized.
+    WindowChange,
+}
+
+/// A runtime that can listen for OS signals whose futures are [`Send`].
+///
+/// See [`RuntimeSignal`] for the documentation of each method.
+pub trait SendRuntimeSignal {
+    /// See [`RuntimeSignal::Signal`].
+    type Signal: SendSignal;
+
+    /// See [`RuntimeSignal::ctrl_c`].
+    fn ctrl_c() -> impl Future<Output = Result<()>> + Send;
+
+    /// See [`RuntimeSignal::signal`].
+    fn signal(kind: SignalKind) -> Result<Self::Signal>;
+}
+
+/// A [`Signal`] that can be sent between threads and whose futures are [`Send`].
+///
+/// See [`Signal`] for the documentation of each method.
+pub trait SendSignal: Stream<Item = ()> + Unpin + Send {
+    /// See [`Signal::recv`].
+    fn recv(&mut self) -> impl Future<Output = Option<()>> + Send {
+        self.next()
+    }
+}
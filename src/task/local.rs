@@ -0,0 +1,223 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+    thread::LocalKey,
+};
+
+/// Declares new task-local keys of type [`TaskLocal`].
+///
+/// The syntax mirrors [`thread_local!`]: any number of `static` declarations, each with optional
+/// attributes and visibility. Unlike thread locals, task locals have no initializer. A value is
+/// instead provided for the duration of a future with [`TaskLocal::scope`].
+#[macro_export]
+macro_rules! task_local {
+    () => {};
+
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty; $($rest:tt)*) => {
+        $(#[$attr])*
+        $vis static $name: $crate::task::TaskLocal<$t> = {
+            ::std::thread_local! {
+                static KEY: ::std::cell::RefCell<::std::option::Option<$t>> =
+                    const { ::std::cell::RefCell::new(::std::option::Option::None) };
+            }
+            $crate::task::TaskLocal::__new(&KEY)
+        };
+
+        $crate::task_local!($($rest)*);
+    };
+}
+
+/// A key for task-local data, declared with [`task_local!`](crate::task_local).
+///
+/// A value is only set while a future created with [`scope`](TaskLocal::scope) is being polled,
+/// which is done by swapping it in and out of a thread-local slot on every poll. This works with
+/// any executor, but also means that values aren't inherited by spawned tasks: a future passed to
+/// [`Executor::spawn`](crate::executor::Executor::spawn) has to be wrapped with `scope` itself.
+pub struct TaskLocal<T: 'static> {
+    key: &'static LocalKey<RefCell<Option<T>>>,
+}
+
+/// An error returned by [`TaskLocal::try_with`] when the value isn't set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("task-local value not set")]
+pub struct AccessError {
+    _private: (),
+}
+
+impl<T: 'static> TaskLocal<T> {
+    #[doc(hidden)]
+    pub const fn __new(key: &'static LocalKey<RefCell<Option<T>>>) -> Self {
+        Self { key }
+    }
+
+    /// Sets the value to `value` while `future` is being polled, including when it is dropped.
+    pub fn scope<F: Future>(&'static self, value: T, future: F) -> Scope<T, F> {
+        Scope {
+            local: self,
+            value: Some(value),
+            future: Some(Box::pin(future)),
+        }
+    }
+
+    /// Sets the value to `value` while `f` is running.
+    pub fn sync_scope<R>(&'static self, value: T, f: impl FnOnce() -> R) -> R {
+        let mut value = Some(value);
+        let _guard = self.enter(&mut value);
+        f()
+    }
+
+    /// Calls `f` with a reference to the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value isn't set, i.e. if called from outside of a [`scope`](TaskLocal::scope).
+    #[track_caller]
+    pub fn with<R>(&'static self, f: impl FnOnce(&T) -> R) -> R {
+        match self.try_with(f) {
+            Ok(output) => output,
+            Err(_) => panic!("task-local value not set; use `TaskLocal::scope` to set it"),
+        }
+    }
+
+    /// Calls `f` with a reference to the value, or returns an error if it isn't set.
+    pub fn try_with<R>(&'static self, f: impl FnOnce(&T) -> R) -> Result<R, AccessError> {
+        self.key.with_borrow(|value| match value {
+            Some(value) => Ok(f(value)),
+            None => Err(AccessError { _private: () }),
+        })
+    }
+
+    /// Returns a copy of the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value isn't set, i.e. if called from outside of a [`scope`](TaskLocal::scope).
+    #[track_caller]
+    pub fn get(&'static self) -> T
+    where
+        T: Clone,
+    {
+        self.with(T::clone)
+    }
+
+    /// Swaps `value` into the thread-local slot until the returned guard is dropped.
+    fn enter<'a>(&'static self, value: &'a mut Option<T>) -> ScopeGuard<'a, T> {
+        self.key.with_borrow_mut(|slot| std::mem::swap(slot, value));
+        ScopeGuard { local: self, value }
+    }
+}
+
+impl<T: 'static> fmt::Debug for TaskLocal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TaskLocal { .. }")
+    }
+}
+
+/// Swaps the value back out of the thread-local slot when dropped.
+struct ScopeGuard<'a, T: 'static> {
+    local: &'static TaskLocal<T>,
+    value: &'a mut Option<T>,
+}
+
+impl<T: 'static> Drop for ScopeGuard<'_, T> {
+    fn drop(&mut self) {
+        self.local
+            .key
+            .with_borrow_mut(|slot| std::mem::swap(slot, self.value));
+    }
+}
+
+/// A future with a task-local value set while it is polled, created with [`TaskLocal::scope`].
+pub struct Scope<T: 'static, F> {
+    local: &'static TaskLocal<T>,
+    value: Option<T>,
+    future: Option<Pin<Box<F>>>,
+}
+
+impl<T: 'static, F: Future> Future for Scope<T, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let _guard = this.local.enter(&mut this.value);
+        let future = this
+            .future
+            .as_mut()
+            .expect("`Scope` polled after completion");
+        let poll = future.as_mut().poll(cx);
+        if poll.is_ready() {
+            // Drop the future while the value is still set, as if it had been dropped early.
+            this.future = None;
+        }
+        poll
+    }
+}
+
+impl<T: 'static, F> Unpin for Scope<T, F> {}
+
+impl<T: 'static, F> Drop for Scope<T, F> {
+    fn drop(&mut self) {
+        if let Some(future) = self.future.take() {
+            let _guard = self.local.enter(&mut self.value);
+            drop(future);
+        }
+    }
+}
+
+impl<T: fmt::Debug + 'static, F> fmt::Debug for Scope<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope")
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_lite::future::{block_on, yield_now};
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    crate::task_local! {
+        static NUMBER: u32;
+    }
+
+    #[test]
+    fn try_with_outside_scope() {
+        assert!(NUMBER.try_with(|_| ()).is_err());
+        assert!(catch_unwind(|| NUMBER.get()).is_err());
+    }
+
+    #[test]
+    fn nested_scopes() {
+        block_on(NUMBER.scope(1, async {
+            assert_eq!(NUMBER.get(), 1);
+            NUMBER
+                .scope(2, async {
+                    assert_eq!(NUMBER.get(), 2);
+                    yield_now().await;
+                    NUMBER.sync_scope(3, || assert_eq!(NUMBER.get(), 3));
+                    assert_eq!(NUMBER.get(), 2);
+                })
+                .await;
+            assert_eq!(NUMBER.get(), 1);
+        }));
+        assert!(NUMBER.try_with(|_| ()).is_err());
+    }
+
+    #[test]
+    fn panicking_scope_restores_value() {
+        NUMBER.sync_scope(1, || {
+            let panic = catch_unwind(|| NUMBER.sync_scope(2, || panic!("boom")));
+            assert!(panic.is_err());
+            assert_eq!(NUMBER.get(), 1);
+
+            let mut scope = NUMBER.scope(3, async { panic!("boom") });
+            let panic = catch_unwind(AssertUnwindSafe(|| block_on(&mut scope)));
+            assert!(panic.is_err());
+            assert_eq!(NUMBER.get(), 1);
+        });
+        assert!(NUMBER.try_with(|_| ()).is_err());
+    }
+}
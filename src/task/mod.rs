@@ -0,0 +1,145 @@
+//! Cooperative scheduling and task-local storage.
+//!
+//! Executors can only switch tasks when the running one yields, so a task that keeps finding work
+//! ready, e.g. a loop over a channel that is never empty, can starve every other task on its
+//! thread. The utilities here let such tasks give up control now and then.
+//!
+//! [`TaskLocal`] values, declared with [`task_local!`](crate::task_local), are scoped to a future
+//! rather than a thread, and work with any executor.
+
+mod local;
+pub use local::*;
+
+use std::{
+    cell::Cell,
+    fmt,
+    future::poll_fn,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A runtime that supports cooperative scheduling.
+pub trait RuntimeTask {
+    /// Yields execution back to the executor, letting other tasks run before this one resumes.
+    fn yield_now() -> impl Future<Output = ()>;
+
+    /// Consumes a unit of the current task's budget, yielding if it has run out.
+    ///
+    /// Inside a [`Budgeted`] future, this uses the budget it was given. Otherwise, tokio applies its
+    /// own per-task budget, while other runtimes have none, so this completes immediately.
+    ///
+    /// Call this in loops that may not otherwise yield.
+    fn consume_budget() -> impl Future<Output = ()>;
+}
+
+/// A runtime that supports cooperative scheduling whose futures are [`Send`].
+///
+/// See [`RuntimeTask`] for the documentation of each method.
+pub trait SendRuntimeTask {
+    /// See [`RuntimeTask::yield_now`].
+    fn yield_now() -> impl Future<Output = ()> + Send;
+
+    /// See [`RuntimeTask::consume_budget`].
+    fn consume_budget() -> impl Future<Output = ()> + Send;
+}
+
+thread_local! {
+    /// The budget left to the [`Budgeted`] future currently being polled on this thread, if any.
+    static BUDGET: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// A future that yields to the executor after every `budget` polls, and is given a fixed budget
+/// every time it is polled, which is consumed by [`consume_budget`](RuntimeTask::consume_budget).
+///
+/// Once the wrapped future has been polled `budget` times, the next poll wakes the task and
+/// yields instead. Likewise, once `budget` calls to `consume_budget` have completed within a single
+/// poll, the next one yields, after which the budget is refilled. This makes any loop calling
+/// `consume_budget` yield every `budget` iterations, regardless of the runtime.
+///
+/// The budget only applies to code running within the future's own poll. Tasks spawned from
+/// inside it aren't affected.
+pub struct Budgeted<F> {
+    future: Pin<Box<F>>,
+    budget: usize,
+    /// The number of polls left before the future yields.
+    polls: usize,
+}
+
+impl<F: Future> Budgeted<F> {
+    /// Wraps `future`, yielding after every `budget` polls of it and giving it `budget` units of
+    /// budget on each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is zero, since the future could then never make progress.
+    pub fn new(future: F, budget: usize) -> Self {
+        assert!(budget > 0, "budget cannot be 0");
+        Self {
+            future: Box::pin(future),
+            budget,
+            polls: budget,
+        }
+    }
+
+    /// Returns the budget the future was created with.
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Consumes the wrapper, returning the wrapped future.
+    pub fn into_inner(self) -> Pin<Box<F>> {
+        self.future
+    }
+}
+
+impl<F: Future> Future for Budgeted<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.polls == 0 {
+            self.polls = self.budget;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        self.polls -= 1;
+        let _guard = BudgetGuard(BUDGET.replace(Some(self.budget)));
+        self.future.as_mut().poll(cx)
+    }
+}
+
+impl<F> fmt::Debug for Budgeted<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Budgeted")
+            .field("budget", &self.budget)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Restores the budget of the enclosing [`Budgeted`] future when dropped.
+struct BudgetGuard(Option<usize>);
+
+impl Drop for BudgetGuard {
+    fn drop(&mut self) {
+        BUDGET.set(self.0);
+    }
+}
+
+/// Consumes a unit of the budget of the enclosing [`Budgeted`] future, or awaits `fallback` if
+/// there is none.
+pub(crate) async fn consume_budget(fallback: impl Future<Output = ()>) {
+    if BUDGET.get().is_none() {
+        return fallback.await;
+    }
+    poll_fn(|cx| match BUDGET.get() {
+        Some(0) => {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+        Some(budget) => {
+            BUDGET.set(Some(budget - 1));
+            Poll::Ready(())
+        }
+        None => Poll::Ready(()),
+    })
+    .await
+}
@@ -0,0 +1,665 @@
+use super::{Elapsed, SendTime, Time};
+use crate::implement::interval::{self, Interval, add};
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    static CLOCK: Arc<Clock> = Arc::new(Clock::new());
+}
+
+/// A [`Time`] driven by a virtual clock, which only moves forward when told to.
+///
+/// Every thread has its own clock, which starts at the time it is first used. It is moved forward
+/// with [`MockClock::advance`], and automatically by a [`MockExecutor`] whenever all of its tasks
+/// are idle, in which case it jumps straight to the next deadline of a sleep or interval.
+///
+/// Deadlines should be computed from [`MockClock::now`] rather than [`Instant::now`], since the
+/// two clocks drift apart as soon as the virtual one is advanced.
+///
+/// `R` is the runtime whose [local executor](crate::executor::local::Executor) is wrapped by
+/// [`MockExecutor`], so that `MockClock<Tokio>` can stand in for [`Tokio`](crate::runtime::Tokio)
+/// in code which needs both a clock and an executor. The clock itself is the same whatever `R` is.
+pub struct MockClock<R = ()>(PhantomData<fn() -> R>);
+
+impl MockClock {
+    /// Returns the current time of this thread's clock.
+    pub fn now() -> Instant {
+        Clock::current().lock().now
+    }
+
+    /// Moves this thread's clock forward by `duration`, waking every sleep and interval whose
+    /// deadline has been reached.
+    pub fn advance(duration: Duration) {
+        let clock = Clock::current();
+        let wakers = {
+            let mut state = clock.lock();
+            state.now = add(state.now, duration);
+            state.expire()
+        };
+        wakers.into_iter().for_each(Waker::wake);
+    }
+}
+
+struct Clock {
+    state: Mutex<State>,
+}
+
+struct State {
+    now: Instant,
+    /// The wakers of pending sleeps, by deadline and id.
+    timers: BTreeMap<(Instant, u64), Waker>,
+    next_id: u64,
+    #[cfg(feature = "local-exec")]
+    tasks: executor::Tasks,
+}
+
+impl Clock {
+    fn new() -> Self {
+        Self {
+            state: Mutex::new(State {
+                now: Instant::now(),
+                timers: BTreeMap::new(),
+                next_id: 0,
+                #[cfg(feature = "local-exec")]
+                tasks: Default::default(),
+            }),
+        }
+    }
+
+    fn current() -> Arc<Self> {
+        CLOCK.with(Arc::clone)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+}
+
+impl State {
+    /// Removes every timer whose deadline has been reached, returning their wakers.
+    fn expire(&mut self) -> Vec<Waker> {
+        let pending = self.timers.split_off(&(self.now, u64::MAX));
+        std::mem::replace(&mut self.timers, pending)
+            .into_values()
+            .collect()
+    }
+}
+
+/// A sleep on a [`MockClock`].
+pub struct Sleep {
+    clock: Arc<Clock>,
+    deadline: Instant,
+    id: u64,
+}
+
+impl Sleep {
+    fn new(clock: Arc<Clock>, deadline: Instant) -> Self {
+        let id = {
+            let mut state = clock.lock();
+            state.next_id += 1;
+            state.next_id
+        };
+        Self {
+            clock,
+            deadline,
+            id,
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = Instant;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.clock.lock();
+        let key = (self.deadline, self.id);
+        if self.deadline <= state.now {
+            state.timers.remove(&key);
+            return Poll::Ready(self.deadline);
+        }
+        match state.timers.get(&key) {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            _ => {
+                state.timers.insert(key, cx.waker().clone());
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        self.clock.lock().timers.remove(&(self.deadline, self.id));
+    }
+}
+
+impl interval::Timer for Sleep {
+    fn at(deadline: Instant) -> Self {
+        Self::new(Clock::current(), deadline)
+    }
+
+    fn set_at(&mut self, deadline: Instant) {
+        let mut state = self.clock.lock();
+        let waker = state.timers.remove(&(self.deadline, self.id));
+        self.deadline = deadline;
+        // Keep waiting with the same waker, as if the sleep had been polled again.
+        if let Some(waker) = waker {
+            if deadline <= state.now {
+                drop(state);
+                waker.wake();
+            } else {
+                state.timers.insert((deadline, self.id), waker);
+            }
+        }
+    }
+
+    fn now(&self) -> Instant {
+        self.clock.lock().now
+    }
+}
+
+impl super::Sleep for Sleep {
+    fn reset(&mut self, deadline: Instant) {
+        interval::Timer::set_at(self, deadline);
+    }
+
+    fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    fn is_elapsed(&self) -> bool {
+        self.deadline <= self.clock.lock().now
+    }
+}
+
+impl<R> Time for MockClock<R> {
+    type Sleep = Sleep;
+
+    fn sleep(duration: Duration) -> Self::Sleep {
+        let clock = Clock::current();
+        let deadline = add(clock.lock().now, duration);
+        Sleep::new(clock, deadline)
+    }
+
+    fn sleep_until(deadline: Instant) -> Self::Sleep {
+        Sleep::new(Clock::current(), deadline)
+    }
+
+    fn interval(period: Duration) -> impl super::Interval {
+        Interval::<Sleep>::new(MockClock::now(), period)
+    }
+
+    fn interval_at(start: Instant, period: Duration) -> impl super::Interval {
+        Interval::<Sleep>::new(start, period)
+    }
+
+    fn timeout<F: Future>(
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> {
+        <Self as Time>::timeout_at(add(MockClock::now(), duration), future)
+    }
+}
+
+impl<R> SendTime for MockClock<R> {
+    type Sleep = Sleep;
+
+    fn sleep(duration: Duration) -> Self::Sleep {
+        <Self as Time>::sleep(duration)
+    }
+
+    fn sleep_until(deadline: Instant) -> Self::Sleep {
+        <Self as Time>::sleep_until(deadline)
+    }
+
+    fn interval(period: Duration) -> impl super::Interval + Send {
+        <Self as Time>::interval(period)
+    }
+
+    fn interval_at(start: Instant, period: Duration) -> impl super::Interval + Send {
+        <Self as Time>::interval_at(start, period)
+    }
+
+    fn timeout<F: Future + Send>(
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send {
+        <Self as SendTime>::timeout_at(add(MockClock::now(), duration), future)
+    }
+}
+
+#[cfg(feature = "local-exec")]
+mod executor {
+    use super::{Clock, MockClock};
+    use crate::executor::{
+        AbortHandle, Handle,
+        local::{Executor, RuntimeExecutor, TaskSet},
+    };
+    use std::{
+        cell::OnceCell,
+        future::{pending, poll_fn},
+        pin::Pin,
+        rc::Rc,
+        sync::{Arc, Mutex},
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    /// A local executor which advances the [`MockClock`] of its thread whenever all of its tasks
+    /// are idle, wrapping the executor `E`.
+    ///
+    /// Tasks are idle when none of them was woken since it was last polled. Blocking functions
+    /// count as busy until they return. Anything else, like I/O, doesn't prevent the clock from
+    /// being advanced.
+    ///
+    /// If every task is idle and there is no pending sleep or interval, [`block_on`](Self::block_on)
+    /// waits forever.
+    pub struct MockExecutor<E> {
+        inner: E,
+        clock: Arc<Clock>,
+    }
+
+    /// The [`TaskSet`] of a [`MockExecutor`].
+    pub struct MockTaskSet<E: Executor, T: 'static>(E::TaskSet<T>);
+
+    impl<E: Executor, T: 'static> Default for MockTaskSet<E, T> {
+        fn default() -> Self {
+            Self(Default::default())
+        }
+    }
+
+    impl<E: Executor> Executor for MockExecutor<E> {
+        type Handle<T: 'static> = E::Handle<T>;
+        type TaskSet<T: 'static> = MockTaskSet<E, T>;
+
+        fn spawn<T: 'static>(&self, future: impl Future<Output = T> + 'static) -> Self::Handle<T> {
+            self.inner.spawn(Tracked::new(self.clock.clone(), future))
+        }
+
+        fn spawn_blocking<T: Send + 'static>(
+            &self,
+            f: impl FnOnce() -> T + Send + 'static,
+        ) -> Self::Handle<T> {
+            let guard = BlockingGuard::new(self.clock.clone());
+            let handle = self.inner.spawn_blocking(f);
+            let abort = Rc::new(OnceCell::<<E::Handle<T> as Handle<T>>::AbortHandle>::new());
+            // Wait for the function in a task of our own, so that the clock can't be advanced
+            // between the function returning and the task awaiting it being woken.
+            let outer = self.spawn({
+                let abort = abort.clone();
+                async move {
+                    let result = handle.await;
+                    drop(guard);
+                    match result {
+                        Ok(output) => output,
+                        Err(error) if error.is_panic() => {
+                            std::panic::resume_unwind(error.into_panic())
+                        }
+                        // Cancel this task too, so that awaiting it yields the same error.
+                        Err(_) => {
+                            if let Some(abort) = abort.get() {
+                                abort.abort();
+                            }
+                            pending().await
+                        }
+                    }
+                }
+            });
+            let _ = abort.set(outer.abort_handle());
+            outer
+        }
+
+        fn block_on<T>(&self, future: impl Future<Output = T>) -> T {
+            let future = Tracked::new(self.clock.clone(), future);
+            self.inner
+                .block_on(futures_lite::future::or(future, drive(&self.clock)))
+        }
+
+        fn new() -> std::io::Result<Self> {
+            Ok(Self {
+                inner: E::new()?,
+                clock: Clock::current(),
+            })
+        }
+    }
+
+    impl<E: Executor, T: 'static> TaskSet<T> for MockTaskSet<E, T> {
+        type Executor = MockExecutor<E>;
+
+        fn spawn(&mut self, executor: &Self::Executor, future: impl Future<Output = T> + 'static) {
+            self.0.spawn(
+                &executor.inner,
+                Tracked::new(executor.clock.clone(), future),
+            );
+        }
+
+        fn join_next(
+            &mut self,
+        ) -> impl Future<Output = Option<Result<T, crate::executor::JoinError>>> {
+            self.0.join_next()
+        }
+
+        fn abort_all(&mut self) {
+            self.0.abort_all();
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    impl<R: RuntimeExecutor> RuntimeExecutor for MockClock<R> {
+        type Executor = MockExecutor<R::Executor>;
+    }
+
+    impl Clock {
+        /// Marks a runnable task as idle.
+        fn settle(&self) {
+            let mut state = self.lock();
+            state.tasks.runnable -= 1;
+            let idle = state.tasks.take_idle();
+            drop(state);
+            if let Some(waker) = idle {
+                waker.wake();
+            }
+        }
+    }
+
+    /// The tasks of the [`MockExecutor`]s using a clock.
+    #[derive(Default)]
+    pub(super) struct Tasks {
+        /// How many tasks were woken and haven't been polled yet, or are being polled.
+        runnable: usize,
+        /// How many blocking functions haven't completed yet.
+        blocking: usize,
+        /// The waker of a [`MockExecutor::block_on`] waiting for every task to become idle.
+        idle: Option<Waker>,
+    }
+
+    impl Tasks {
+        fn is_idle(&self) -> bool {
+            self.runnable == 0 && self.blocking == 0
+        }
+
+        /// Returns the waker of the executor waiting for every task to become idle, if they are.
+        fn take_idle(&mut self) -> Option<Waker> {
+            if self.is_idle() {
+                self.idle.take()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Never completes, but advances the clock to the next deadline whenever every task is idle.
+    fn drive<T>(clock: &Arc<Clock>) -> impl Future<Output = T> {
+        let clock = clock.clone();
+        poll_fn(move |cx| {
+            let mut state = clock.lock();
+            let next = state.timers.keys().next().map(|&(deadline, _)| deadline);
+            let Some(deadline) = next.filter(|_| state.tasks.is_idle()) else {
+                state.tasks.idle = Some(cx.waker().clone());
+                return Poll::Pending;
+            };
+            state.now = state.now.max(deadline);
+            let wakers = state.expire();
+            drop(state);
+            wakers.into_iter().for_each(Waker::wake);
+            // Check again once the woken tasks are runnable, in case none of them are ours.
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        })
+    }
+
+    /// A task of a [`MockExecutor`], which keeps track of whether it is runnable.
+    struct Tracked<F> {
+        future: Pin<Box<F>>,
+        task: Arc<TrackedTask>,
+        waker: Waker,
+    }
+
+    struct TrackedTask {
+        clock: Arc<Clock>,
+        state: Mutex<TaskState>,
+    }
+
+    struct TaskState {
+        /// The executor's waker for the task.
+        waker: Option<Waker>,
+        /// Whether the task was woken and counted as runnable.
+        scheduled: bool,
+        /// Whether the task was dropped, after which it can't be woken anymore.
+        dropped: bool,
+    }
+
+    impl<F: Future> Tracked<F> {
+        fn new(clock: Arc<Clock>, future: F) -> Self {
+            // The task is runnable until it is first polled.
+            clock.lock().tasks.runnable += 1;
+            let task = Arc::new(TrackedTask {
+                clock,
+                state: Mutex::new(TaskState {
+                    waker: None,
+                    scheduled: true,
+                    dropped: false,
+                }),
+            });
+            Self {
+                future: Box::pin(future),
+                waker: Waker::from(task.clone()),
+                task,
+            }
+        }
+    }
+
+    impl<F: Future> Future for Tracked<F> {
+        type Output = F::Output;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.get_mut();
+            let woken = {
+                let mut state = this.task.state.lock().unwrap();
+                if !state
+                    .waker
+                    .as_ref()
+                    .is_some_and(|waker| waker.will_wake(cx.waker()))
+                {
+                    state.waker = Some(cx.waker().clone());
+                }
+                std::mem::take(&mut state.scheduled)
+            };
+            let poll = this
+                .future
+                .as_mut()
+                .poll(&mut Context::from_waker(&this.waker));
+            // Only settle once polled, so that the clock isn't advanced while the task runs.
+            if woken {
+                this.task.clock.settle();
+            }
+            poll
+        }
+    }
+
+    impl<F> Drop for Tracked<F> {
+        fn drop(&mut self) {
+            let scheduled = {
+                let mut state = self.task.state.lock().unwrap();
+                state.dropped = true;
+                state.waker = None;
+                std::mem::take(&mut state.scheduled)
+            };
+            if scheduled {
+                self.task.clock.settle();
+            }
+        }
+    }
+
+    impl Wake for TrackedTask {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            let mut state = self.state.lock().unwrap();
+            if state.dropped {
+                return;
+            }
+            if !state.scheduled {
+                state.scheduled = true;
+                self.clock.lock().tasks.runnable += 1;
+            }
+            let waker = state.waker.clone();
+            drop(state);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Keeps the clock from being advanced until dropped.
+    struct BlockingGuard(Arc<Clock>);
+
+    impl BlockingGuard {
+        fn new(clock: Arc<Clock>) -> Self {
+            clock.lock().tasks.blocking += 1;
+            Self(clock)
+        }
+    }
+
+    impl Drop for BlockingGuard {
+        fn drop(&mut self) {
+            let mut state = self.0.lock();
+            state.tasks.blocking -= 1;
+            let idle = state.tasks.take_idle();
+            drop(state);
+            if let Some(waker) = idle {
+                waker.wake();
+            }
+        }
+    }
+}
+#[cfg(feature = "local-exec")]
+pub use executor::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::{Interval as _, MissedTickBehavior};
+    use futures_lite::Stream;
+    use std::task::Wake;
+
+    /// Records the id of every sleep it is woken for.
+    struct Recorder {
+        id: usize,
+        woken: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl Wake for Recorder {
+        fn wake(self: Arc<Self>) {
+            self.woken.lock().unwrap().push(self.id);
+        }
+    }
+
+    fn poll_tick(interval: &mut (impl Stream<Item = Instant> + Unpin)) -> Poll<Instant> {
+        let mut cx = Context::from_waker(Waker::noop());
+        Pin::new(interval)
+            .poll_next(&mut cx)
+            .map(|tick| tick.unwrap())
+    }
+
+    #[test]
+    fn sleep_resolves_to_its_deadline() {
+        let deadline = MockClock::now() + Duration::from_secs(1);
+        let mut sleep = <MockClock as Time>::sleep_until(deadline);
+        MockClock::advance(Duration::from_secs(3));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert_eq!(Pin::new(&mut sleep).poll(&mut cx), Poll::Ready(deadline));
+    }
+
+    #[test]
+    fn equal_deadlines_wake_in_creation_order() {
+        let start = MockClock::now();
+        let woken = Arc::new(Mutex::new(Vec::new()));
+        let mut sleeps = [1, 1, 2, 1]
+            .map(|secs| <MockClock as Time>::sleep_until(start + Duration::from_secs(secs)));
+        for (id, sleep) in sleeps.iter_mut().enumerate() {
+            let waker = Waker::from(Arc::new(Recorder {
+                id,
+                woken: woken.clone(),
+            }));
+            assert!(
+                Pin::new(sleep)
+                    .poll(&mut Context::from_waker(&waker))
+                    .is_pending()
+            );
+        }
+
+        MockClock::advance(Duration::from_secs(1));
+        assert_eq!(*woken.lock().unwrap(), [0, 1, 3]);
+        MockClock::advance(Duration::from_secs(1));
+        assert_eq!(*woken.lock().unwrap(), [0, 1, 3, 2]);
+    }
+
+    /// Misses three and a half ticks of a one second interval, returning when the ticks yielded
+    /// right away were due and when the next one is, in milliseconds from the start.
+    fn catch_up(behavior: MissedTickBehavior) -> (Vec<u128>, u128) {
+        let start = MockClock::now();
+        let mut interval = <MockClock as Time>::interval_at(start, Duration::from_secs(1));
+        interval.set_missed_tick_behavior(behavior);
+        assert_eq!(poll_tick(&mut interval), Poll::Ready(start));
+
+        MockClock::advance(Duration::from_millis(3500));
+        let mut missed = Vec::new();
+        while let Poll::Ready(tick) = poll_tick(&mut interval) {
+            missed.push((tick - start).as_millis());
+        }
+        loop {
+            MockClock::advance(Duration::from_millis(100));
+            if let Poll::Ready(tick) = poll_tick(&mut interval) {
+                assert_eq!(tick, MockClock::now());
+                return (missed, (tick - start).as_millis());
+            }
+        }
+    }
+
+    #[test]
+    fn interval_catches_up_after_missed_ticks() {
+        assert_eq!(
+            catch_up(MissedTickBehavior::Burst),
+            (vec![1000, 2000, 3000], 4000)
+        );
+        assert_eq!(catch_up(MissedTickBehavior::Delay), (vec![1000], 4500));
+        assert_eq!(catch_up(MissedTickBehavior::Skip), (vec![1000], 4000));
+    }
+
+    #[cfg(all(feature = "local-exec", feature = "futures"))]
+    #[test]
+    fn advances_only_when_every_task_is_idle() {
+        use crate::executor::local::{Executor, RuntimeExecutor};
+        use futures_lite::future::yield_now;
+
+        let executor =
+            <MockClock<crate::runtime::Futures> as RuntimeExecutor>::Executor::new().unwrap();
+        let start = MockClock::now();
+        let sleeper = executor.spawn(async {
+            <MockClock as Time>::sleep(Duration::from_secs(1)).await;
+            MockClock::now()
+        });
+        let blocking = executor.spawn_blocking(|| std::thread::sleep(Duration::from_millis(50)));
+
+        executor.block_on(async {
+            for _ in 0..100 {
+                yield_now().await;
+                assert_eq!(MockClock::now(), start);
+            }
+            blocking.await.unwrap();
+            assert_eq!(MockClock::now(), start);
+            assert_eq!(sleeper.await.unwrap(), start + Duration::from_secs(1));
+        });
+    }
+}
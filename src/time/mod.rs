@@ -0,0 +1,232 @@
+//! Asynchronous timing.
+//!
+//! [`MockClock`] is a [`Time`] driven by a virtual clock rather than the system's, for testing
+//! code that sleeps without having to wait.
+
+mod mock;
+pub use mock::MockClock;
+#[cfg(feature = "local-exec")]
+pub use mock::{MockExecutor, MockTaskSet};
+
+use futures_lite::{Stream, StreamExt, future, stream};
+use std::time::{Duration, Instant};
+
+/// An error returned when a future or stream doesn't complete before its deadline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("deadline has elapsed")]
+pub struct Elapsed {
+    _private: (),
+}
+
+impl Elapsed {
+    pub(crate) const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+pub trait Time {
+    /// The future returned by [`sleep`](Time::sleep) and [`sleep_until`](Time::sleep_until).
+    type Sleep: Sleep;
+
+    /// Sleep for the specified duration, resolving to its deadline (see [`Sleep`]).
+    fn sleep(duration: Duration) -> Self::Sleep;
+
+    /// Sleep until the specified instant, resolving to its deadline (see [`Sleep`]).
+    fn sleep_until(deadline: Instant) -> Self::Sleep;
+
+    /// Create an [`Interval`] that ticks every `period`, starting immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    fn interval(period: Duration) -> impl Interval;
+
+    /// Create an [`Interval`] that ticks every `period`, starting at `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    fn interval_at(start: Instant, period: Duration) -> impl Interval;
+
+    /// Await `future`, failing with [`Elapsed`] if it doesn't complete within `duration`.
+    ///
+    /// The deadline is computed when this is called, not when the returned future is first polled.
+    fn timeout<F: Future>(
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> {
+        let deadline = Instant::now().checked_add(duration);
+        async move {
+            match deadline {
+                Some(deadline) => Self::timeout_at(deadline, future).await,
+                // The deadline is too far in the future to ever be reached.
+                None => Ok(future.await),
+            }
+        }
+    }
+
+    /// Await `future`, failing with [`Elapsed`] if it doesn't complete before `deadline`.
+    ///
+    /// `future` is polled before the deadline is checked, so it can still complete if it is ready
+    /// by the time it is first polled.
+    fn timeout_at<F: Future>(
+        deadline: Instant,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> {
+        future::or(async { Ok(future.await) }, async move {
+            Self::sleep_until(deadline).await;
+            Err(Elapsed::new())
+        })
+    }
+}
+
+/// A future which completes at a deadline, which can be changed while it is pending.
+///
+/// The sleep resolves to its deadline rather than to the time at which it was woken, which may be
+/// later. This is the same for every runtime.
+///
+/// Resetting a sleep is cheaper than replacing it, e.g. for a keep-alive timer which is pushed back
+/// every time a message is received.
+pub trait Sleep: Future<Output = Instant> + Unpin {
+    /// Changes the deadline of the sleep.
+    ///
+    /// This can be called after the sleep has completed, in which case it can be awaited again.
+    fn reset(&mut self, deadline: Instant);
+
+    /// Returns the deadline of the sleep.
+    fn deadline(&self) -> Instant;
+
+    /// Returns `true` if the deadline has been reached.
+    fn is_elapsed(&self) -> bool;
+}
+
+/// A stream of instants, yielded once per period.
+///
+/// Each item is the instant at which the tick was scheduled, which may be earlier than when it is
+/// actually yielded. The stream never ends.
+pub trait Interval: Stream<Item = Instant> + Unpin {
+    /// Returns how the interval behaves when ticks are missed.
+    fn missed_tick_behavior(&self) -> MissedTickBehavior;
+
+    /// Sets how the interval behaves when ticks are missed.
+    fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior);
+
+    /// Reschedules the next tick to one period from now.
+    fn reset(&mut self);
+
+    /// Reschedules the next tick to `deadline`. Following ticks are scheduled relative to it.
+    fn reset_at(&mut self, deadline: Instant);
+
+    /// Returns the period of the interval.
+    fn period(&self) -> Duration;
+}
+
+/// How an [`Interval`] catches up when ticks are missed, i.e. when it isn't polled for long
+/// enough that one or more ticks should already have been yielded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissedTickBehavior {
+    /// Yield the missed ticks as fast as possible, until the interval is back on schedule.
+    #[default]
+    Burst,
+    /// Yield one tick right away, and schedule the following ones one period from then.
+    Delay,
+    /// Yield one tick right away, and skip the other missed ticks so that the following ones stay
+    /// on the original schedule.
+    Skip,
+}
+
+/// A [`Time`] whose futures and streams are [`Send`].
+///
+/// See [`Time`] for the documentation of each method.
+pub trait SendTime {
+    /// See [`Time::Sleep`].
+    type Sleep: Sleep + Send;
+
+    /// See [`Time::sleep`].
+    fn sleep(duration: Duration) -> Self::Sleep;
+
+    /// See [`Time::sleep_until`].
+    fn sleep_until(deadline: Instant) -> Self::Sleep;
+
+    /// See [`Time::interval`].
+    fn interval(period: Duration) -> impl Interval + Send;
+
+    /// See [`Time::interval_at`].
+    fn interval_at(start: Instant, period: Duration) -> impl Interval + Send;
+
+    /// See [`Time::timeout`].
+    fn timeout<F: Future + Send>(
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send {
+        let deadline = Instant::now().checked_add(duration);
+        async move {
+            match deadline {
+                Some(deadline) => Self::timeout_at(deadline, future).await,
+                None => Ok(future.await),
+            }
+        }
+    }
+
+    /// See [`Time::timeout_at`].
+    fn timeout_at<F: Future + Send>(
+        deadline: Instant,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send {
+        future::or(async { Ok(future.await) }, async move {
+            Self::sleep_until(deadline).await;
+            Err(Elapsed::new())
+        })
+    }
+}
+
+/// Timeouts for any future, using the timer of the runtime `R`, as in
+/// `future.timeout::<Tokio>(duration)`.
+///
+/// The returned futures are only known to be [`Send`] when `R` is a concrete runtime. Generic code
+/// which needs them to be should use [`SendTime::timeout`] instead.
+pub trait TimeoutExt: Future + Sized {
+    /// See [`Time::timeout`].
+    fn timeout<R: Time>(
+        self,
+        duration: Duration,
+    ) -> impl Future<Output = Result<Self::Output, Elapsed>> {
+        R::timeout(duration, self)
+    }
+
+    /// See [`Time::timeout_at`].
+    fn timeout_at<R: Time>(
+        self,
+        deadline: Instant,
+    ) -> impl Future<Output = Result<Self::Output, Elapsed>> {
+        R::timeout_at(deadline, self)
+    }
+}
+
+impl<F: Future> TimeoutExt for F {}
+
+/// Timeouts for any stream, using the timer of the runtime `R`.
+///
+/// As with [`TimeoutExt`], the returned streams are only known to be [`Send`] when `R` is a
+/// concrete runtime.
+pub trait StreamTimeoutExt: Stream + Sized {
+    /// Yield the items of the stream, or [`Elapsed`] whenever the next item isn't produced within
+    /// `duration`.
+    ///
+    /// The timer restarts every time the stream is polled for a new item, including after an
+    /// [`Elapsed`] error, so a stream that has timed out can still yield more items afterwards.
+    fn timeout<R: Time>(
+        self,
+        duration: Duration,
+    ) -> impl Stream<Item = Result<Self::Item, Elapsed>> {
+        stream::unfold(Box::pin(self), move |mut stream| async move {
+            match R::timeout(duration, stream.next()).await {
+                Ok(Some(item)) => Some((Ok(item), stream)),
+                Ok(None) => None,
+                Err(elapsed) => Some((Err(elapsed), stream)),
+            }
+        })
+    }
+}
+
+impl<S: Stream> StreamTimeoutExt for S {}